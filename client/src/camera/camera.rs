@@ -104,7 +104,7 @@ pub fn orbit_system(
     mouse_motion: Res<AccumulatedMouseMotion>,
     mut mouse_wheel: EventReader<MouseWheel>,
     time: Res<Time>,
-    ship_query: Query<&Transform, (With<Ship>, Without<Camera3d>)>,
+    ship_query: Query<&GlobalTransform, (With<Ship>, Without<Camera3d>)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     ui_interaction_query: Query<&Interaction, With<Node>>,
     window_manager: Res<WindowManager>,
@@ -115,7 +115,8 @@ pub fn orbit_system(
   let zoom_threshold = 0.01;
 
   let window_focused = window_query.single().map(|w| w.focused).unwrap_or(true);
-  let target = ship_query.single().map_or(Vec3::ZERO, |t| t.translation);
+  // Ships sit in their node's frame, so follow where they end up in the world
+  let target = ship_query.single().map_or(Vec3::ZERO, |t| t.translation());
 
   // Check if mouse is over any UI element
   let mouse_over_ui = ui_interaction_query.iter().any(|interaction| {
@@ -26,6 +26,10 @@ pub struct Ship;
 #[allow(dead_code)]
 pub struct PlayerControlled;
 
+/// The entity the player picked, e.g. to inspect it
+#[derive(Component)]
+pub struct Selected;
+
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct Mass(pub f64);
@@ -34,49 +38,66 @@ pub struct Mass(pub f64);
 #[allow(dead_code)]
 pub struct MaxThrust(pub DVec3);
 
-/// Component to handle smooth interpolation between server rotation updates
-#[derive(Component, Debug)]
+/// How far the ship's sensors reach, in metres
+#[derive(Component)]
 #[allow(dead_code)]
-pub struct RotationInterpolation {
-    /// The rotation at the start of the current interpolation period
+pub struct SensorRange(pub f64);
+
+/// Smooths movement between server updates. Each new server state is reached
+/// over one observed update interval, starting from wherever the entity is
+/// drawn; once there, the position is dead-reckoned from the server velocity
+/// until the next state arrives.
+#[derive(Component, Debug)]
+pub struct MotionInterpolation {
+    /// Where the entity was drawn when the latest server state arrived
+    pub start_position: Vec3,
     pub start_rotation: Quat,
-    /// The target rotation we're interpolating towards
+    /// The latest server state
+    pub target_position: Vec3,
     pub target_rotation: Quat,
-    /// When this interpolation period started
+    /// Server velocity, used past the target
+    pub velocity: Vec3,
+    /// When the latest server state arrived
     pub start_time: f64,
-    /// How long this interpolation should take (server update interval)
+    /// How long reaching the target should take (observed update interval)
     pub duration: f64,
 }
 
-impl RotationInterpolation {
-    /// Create a new interpolation from current to target rotation
-    #[allow(dead_code)]
-    pub fn new(current: Quat, target: Quat, start_time: f64, duration: f64) -> Self {
+impl MotionInterpolation {
+    /// Keep dead-reckoning for at most this many update intervals
+    const MAX_EXTRAPOLATION: f64 = 2.0;
+
+    /// Start moving from the drawn transform toward a new server state
+    pub fn new(current: &Transform, target_position: Vec3, target_rotation: Quat, velocity: Vec3, start_time: f64, duration: f64) -> Self {
         Self {
-            start_rotation: current,
-            target_rotation: target,
+            start_position: current.translation,
+            start_rotation: current.rotation,
+            target_position,
+            target_rotation,
+            velocity,
             start_time,
             duration,
         }
     }
 
-    /// Get the interpolated rotation at the given time
-    #[allow(dead_code)]
-    pub fn get_rotation_at_time(&self, current_time: f64) -> Quat {
+    /// The position and rotation to draw at the given time
+    pub fn sample(&self, current_time: f64) -> (Vec3, Quat) {
+        let elapsed = current_time - self.start_time;
         if self.duration <= 0.0 {
-            return self.target_rotation;
+            return (self.target_position, self.target_rotation);
         }
 
-        let elapsed = current_time - self.start_time;
-        let t = (elapsed / self.duration).clamp(0.0, 1.0);
-        
-        // Use spherical linear interpolation for smooth rotation
-        self.start_rotation.slerp(self.target_rotation, t as f32)
-    }
+        if elapsed < self.duration {
+            let t = (elapsed / self.duration).clamp(0.0, 1.0) as f32;
+            return (
+                self.start_position.lerp(self.target_position, t),
+                // Use spherical linear interpolation for smooth rotation
+                self.start_rotation.slerp(self.target_rotation, t),
+            );
+        }
 
-    /// Check if this interpolation is complete
-    #[allow(dead_code)]
-    pub fn is_complete(&self, current_time: f64) -> bool {
-        current_time >= self.start_time + self.duration
+        // Past the target: carry on at the server velocity, but not forever
+        let ahead = (elapsed - self.duration).min(self.duration * Self::MAX_EXTRAPOLATION);
+        (self.target_position + self.velocity * ahead as f32, self.target_rotation)
     }
 }
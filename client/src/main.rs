@@ -1,6 +1,7 @@
 use bevy::{
   //diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
   input_focus::tab_navigation::TabGroup,
+  picking::mesh_picking::MeshPickingPlugin,
   prelude::*,
   winit::WinitSettings,
 };
@@ -135,6 +136,8 @@ fn main() {
     .add_plugins((
       // FrameTimeDiagnosticsPlugin::default(),
       // LogDiagnosticsPlugin::default(),
+      // Clicking ships selects them
+      MeshPickingPlugin,
       synchronizer_plugin,
       ui_plugin,
       camera_plugin,
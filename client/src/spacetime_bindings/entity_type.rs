@@ -21,7 +21,10 @@ pub struct Entity {
     pub relative_rotational_velocity: DVec3,
     pub mass: f64,
     pub max_impulse: DVec3,
+    pub propellant: f64,
+    pub specific_impulse: f64,
     pub entity_type: EntityType,
+    pub ship_class_id: Option<u64>,
 }
 
 impl __sdk::InModule for Entity {
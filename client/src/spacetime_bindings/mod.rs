@@ -4,37 +4,189 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod add_waypoint_reducer;
+pub mod admin_table;
+pub mod admin_type;
 pub mod animation_counter_table;
 pub mod animation_counter_type;
+pub mod cancel_maneuver_reducer;
+pub mod cancel_path_prediction_reducer;
+pub mod create_formation_reducer;
+pub mod create_node_reducer;
 pub mod d_quat_type;
 pub mod d_vec_3_type;
+pub mod delete_node_reducer;
+pub mod delete_snapshot_reducer;
+pub mod disband_formation_reducer;
+pub mod entity_cell_table;
+pub mod entity_cell_type;
 pub mod entity_table;
 pub mod entity_type;
 pub mod entity_type_type;
+pub mod execute_maneuvers_reducer;
+pub mod export_world_reducer;
+pub mod formation_member_table;
+pub mod formation_member_type;
+pub mod formation_table;
+pub mod formation_type;
+pub mod grant_admin_reducer;
+pub mod import_world_reducer;
+pub mod integrator_type;
+pub mod join_formation_reducer;
+pub mod leave_formation_reducer;
+pub mod load_scenario_reducer;
+pub mod maneuver_frame_type;
+pub mod maneuver_schedule_table;
+pub mod maneuver_schedule_type;
+pub mod maneuver_status_type;
+pub mod maneuver_table;
+pub mod maneuver_type;
+pub mod migrate_reducer;
+pub mod move_node_reducer;
 pub mod node_name_type;
 pub mod node_table;
 pub mod node_type;
+pub mod path_prediction_table;
+pub mod path_prediction_type;
+pub mod pilot_input_reducer;
+pub mod pilot_input_table;
+pub mod pilot_input_type;
+pub mod pilot_table;
+pub mod pilot_type;
+pub mod plan_maneuver_reducer;
+pub mod plan_transfer_reducer;
+pub mod predicted_path_table;
+pub mod predicted_path_type;
+pub mod release_control_reducer;
+pub mod request_path_prediction_reducer;
+pub mod reset_ship_reducer;
+pub mod restore_world_reducer;
+pub mod schema_version_table;
+pub mod schema_version_type;
+pub mod set_integrator_reducer;
+pub mod ship_class_table;
+pub mod ship_class_type;
+pub mod spawn_ship_reducer;
+pub mod take_control_reducer;
 pub mod test_reducer_reducer;
 pub mod test_reducer_schedule_table;
 pub mod test_reducer_schedule_type;
+pub mod transfer_method_type;
+pub mod transfer_plan_table;
+pub mod transfer_plan_type;
 pub mod waypoint_table;
 pub mod waypoint_type;
-
+pub mod world_settings_table;
+pub mod world_settings_type;
+pub mod world_snapshot_row_type;
+pub mod world_snapshot_table;
+
+pub use add_waypoint_reducer::{add_waypoint, set_flags_for_add_waypoint, AddWaypointCallbackId};
+pub use admin_table::*;
+pub use admin_type::Admin;
 pub use animation_counter_table::*;
 pub use animation_counter_type::AnimationCounter;
+pub use cancel_maneuver_reducer::{
+    cancel_maneuver, set_flags_for_cancel_maneuver, CancelManeuverCallbackId,
+};
+pub use cancel_path_prediction_reducer::{
+    cancel_path_prediction, set_flags_for_cancel_path_prediction, CancelPathPredictionCallbackId,
+};
+pub use create_formation_reducer::{
+    create_formation, set_flags_for_create_formation, CreateFormationCallbackId,
+};
+pub use create_node_reducer::{create_node, set_flags_for_create_node, CreateNodeCallbackId};
 pub use d_quat_type::DQuat;
 pub use d_vec_3_type::DVec3;
+pub use delete_node_reducer::{delete_node, set_flags_for_delete_node, DeleteNodeCallbackId};
+pub use delete_snapshot_reducer::{
+    delete_snapshot, set_flags_for_delete_snapshot, DeleteSnapshotCallbackId,
+};
+pub use disband_formation_reducer::{
+    disband_formation, set_flags_for_disband_formation, DisbandFormationCallbackId,
+};
+pub use entity_cell_table::*;
+pub use entity_cell_type::EntityCell;
 pub use entity_table::*;
 pub use entity_type::Entity;
 pub use entity_type_type::EntityType;
+pub use execute_maneuvers_reducer::{
+    execute_maneuvers, set_flags_for_execute_maneuvers, ExecuteManeuversCallbackId,
+};
+pub use export_world_reducer::{export_world, set_flags_for_export_world, ExportWorldCallbackId};
+pub use formation_member_table::*;
+pub use formation_member_type::FormationMember;
+pub use formation_table::*;
+pub use formation_type::Formation;
+pub use grant_admin_reducer::{grant_admin, set_flags_for_grant_admin, GrantAdminCallbackId};
+pub use import_world_reducer::{import_world, set_flags_for_import_world, ImportWorldCallbackId};
+pub use integrator_type::Integrator;
+pub use join_formation_reducer::{
+    join_formation, set_flags_for_join_formation, JoinFormationCallbackId,
+};
+pub use leave_formation_reducer::{
+    leave_formation, set_flags_for_leave_formation, LeaveFormationCallbackId,
+};
+pub use load_scenario_reducer::{
+    load_scenario, set_flags_for_load_scenario, LoadScenarioCallbackId,
+};
+pub use maneuver_frame_type::ManeuverFrame;
+pub use maneuver_schedule_table::*;
+pub use maneuver_schedule_type::ManeuverSchedule;
+pub use maneuver_status_type::ManeuverStatus;
+pub use maneuver_table::*;
+pub use maneuver_type::Maneuver;
+pub use migrate_reducer::{migrate, set_flags_for_migrate, MigrateCallbackId};
+pub use move_node_reducer::{move_node, set_flags_for_move_node, MoveNodeCallbackId};
 pub use node_name_type::NodeName;
 pub use node_table::*;
 pub use node_type::Node;
+pub use path_prediction_table::*;
+pub use path_prediction_type::PathPrediction;
+pub use pilot_input_reducer::{pilot_input, set_flags_for_pilot_input, PilotInputCallbackId};
+pub use pilot_input_table::*;
+pub use pilot_input_type::PilotInput;
+pub use pilot_table::*;
+pub use pilot_type::Pilot;
+pub use plan_maneuver_reducer::{
+    plan_maneuver, set_flags_for_plan_maneuver, PlanManeuverCallbackId,
+};
+pub use plan_transfer_reducer::{
+    plan_transfer, set_flags_for_plan_transfer, PlanTransferCallbackId,
+};
+pub use predicted_path_table::*;
+pub use predicted_path_type::PredictedPath;
+pub use release_control_reducer::{
+    release_control, set_flags_for_release_control, ReleaseControlCallbackId,
+};
+pub use request_path_prediction_reducer::{
+    request_path_prediction, set_flags_for_request_path_prediction, RequestPathPredictionCallbackId,
+};
+pub use reset_ship_reducer::{reset_ship, set_flags_for_reset_ship, ResetShipCallbackId};
+pub use restore_world_reducer::{
+    restore_world, set_flags_for_restore_world, RestoreWorldCallbackId,
+};
+pub use schema_version_table::*;
+pub use schema_version_type::SchemaVersion;
+pub use set_integrator_reducer::{
+    set_flags_for_set_integrator, set_integrator, SetIntegratorCallbackId,
+};
+pub use ship_class_table::*;
+pub use ship_class_type::ShipClass;
+pub use spawn_ship_reducer::{set_flags_for_spawn_ship, spawn_ship, SpawnShipCallbackId};
+pub use take_control_reducer::{set_flags_for_take_control, take_control, TakeControlCallbackId};
 pub use test_reducer_reducer::{set_flags_for_test_reducer, test_reducer, TestReducerCallbackId};
 pub use test_reducer_schedule_table::*;
 pub use test_reducer_schedule_type::TestReducerSchedule;
+pub use transfer_method_type::TransferMethod;
+pub use transfer_plan_table::*;
+pub use transfer_plan_type::TransferPlan;
 pub use waypoint_table::*;
 pub use waypoint_type::Waypoint;
+pub use world_settings_table::*;
+pub use world_settings_type::WorldSettings;
+pub use world_snapshot_row_type::WorldSnapshotRow;
+pub use world_snapshot_table::*;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -44,7 +196,110 @@ pub use waypoint_type::Waypoint;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    TestReducer { arg: TestReducerSchedule },
+    AddWaypoint {
+        entity_id: u64,
+        target_position: DVec3,
+    },
+    CancelManeuver {
+        maneuver_id: u64,
+    },
+    CancelPathPrediction {
+        entity_id: u64,
+    },
+    CreateFormation {
+        name: String,
+        leader_id: u64,
+    },
+    CreateNode {
+        name: String,
+        parent: Option<NodeName>,
+        position: DVec3,
+        velocity: DVec3,
+        mass: f64,
+        radius: f64,
+    },
+    DeleteNode {
+        name: String,
+    },
+    DeleteSnapshot {
+        name: String,
+    },
+    DisbandFormation {
+        name: String,
+    },
+    ExecuteManeuvers {
+        arg: ManeuverSchedule,
+    },
+    ExportWorld {
+        name: String,
+    },
+    GrantAdmin {
+        identity: __sdk::Identity,
+    },
+    ImportWorld {
+        document: String,
+    },
+    JoinFormation {
+        name: String,
+        entity_id: u64,
+        offset: DVec3,
+    },
+    LeaveFormation {
+        entity_id: u64,
+    },
+    LoadScenario {
+        name: String,
+    },
+    Migrate,
+    MoveNode {
+        name: String,
+        parent: Option<NodeName>,
+        position: DVec3,
+        velocity: DVec3,
+    },
+    PilotInput {
+        entity_id: u64,
+        sequence: u32,
+        thrust: DVec3,
+    },
+    PlanManeuver {
+        entity_id: u64,
+        execute_at: __sdk::Timestamp,
+        delta_v: DVec3,
+        frame: ManeuverFrame,
+    },
+    PlanTransfer {
+        entity_id: u64,
+        destination: NodeName,
+        method: Option<TransferMethod>,
+    },
+    ReleaseControl {
+        entity_id: u64,
+    },
+    RequestPathPrediction {
+        entity_id: u64,
+        horizon: f64,
+        resolution: f64,
+    },
+    ResetShip,
+    RestoreWorld {
+        name: String,
+    },
+    SetIntegrator {
+        integrator: Integrator,
+    },
+    SpawnShip {
+        class: String,
+        designation: String,
+        node: NodeName,
+        position: DVec3,
+    },
+    TakeControl {
+        entity_id: u64,
+    },
+    TestReducer {
+        arg: TestReducerSchedule,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -54,6 +309,33 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AddWaypoint { .. } => "add_waypoint",
+            Reducer::CancelManeuver { .. } => "cancel_maneuver",
+            Reducer::CancelPathPrediction { .. } => "cancel_path_prediction",
+            Reducer::CreateFormation { .. } => "create_formation",
+            Reducer::CreateNode { .. } => "create_node",
+            Reducer::DeleteNode { .. } => "delete_node",
+            Reducer::DeleteSnapshot { .. } => "delete_snapshot",
+            Reducer::DisbandFormation { .. } => "disband_formation",
+            Reducer::ExecuteManeuvers { .. } => "execute_maneuvers",
+            Reducer::ExportWorld { .. } => "export_world",
+            Reducer::GrantAdmin { .. } => "grant_admin",
+            Reducer::ImportWorld { .. } => "import_world",
+            Reducer::JoinFormation { .. } => "join_formation",
+            Reducer::LeaveFormation { .. } => "leave_formation",
+            Reducer::LoadScenario { .. } => "load_scenario",
+            Reducer::Migrate => "migrate",
+            Reducer::MoveNode { .. } => "move_node",
+            Reducer::PilotInput { .. } => "pilot_input",
+            Reducer::PlanManeuver { .. } => "plan_maneuver",
+            Reducer::PlanTransfer { .. } => "plan_transfer",
+            Reducer::ReleaseControl { .. } => "release_control",
+            Reducer::RequestPathPrediction { .. } => "request_path_prediction",
+            Reducer::ResetShip => "reset_ship",
+            Reducer::RestoreWorld { .. } => "restore_world",
+            Reducer::SetIntegrator { .. } => "set_integrator",
+            Reducer::SpawnShip { .. } => "spawn_ship",
+            Reducer::TakeControl { .. } => "take_control",
             Reducer::TestReducer { .. } => "test_reducer",
         }
     }
@@ -62,6 +344,148 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "add_waypoint" => Ok(
+                __sdk::parse_reducer_args::<add_waypoint_reducer::AddWaypointArgs>(
+                    "add_waypoint",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cancel_maneuver" => Ok(__sdk::parse_reducer_args::<
+                cancel_maneuver_reducer::CancelManeuverArgs,
+            >("cancel_maneuver", &value.args)?
+            .into()),
+            "cancel_path_prediction" => Ok(__sdk::parse_reducer_args::<
+                cancel_path_prediction_reducer::CancelPathPredictionArgs,
+            >("cancel_path_prediction", &value.args)?
+            .into()),
+            "create_formation" => Ok(__sdk::parse_reducer_args::<
+                create_formation_reducer::CreateFormationArgs,
+            >("create_formation", &value.args)?
+            .into()),
+            "create_node" => Ok(
+                __sdk::parse_reducer_args::<create_node_reducer::CreateNodeArgs>(
+                    "create_node",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "delete_node" => Ok(
+                __sdk::parse_reducer_args::<delete_node_reducer::DeleteNodeArgs>(
+                    "delete_node",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "delete_snapshot" => Ok(__sdk::parse_reducer_args::<
+                delete_snapshot_reducer::DeleteSnapshotArgs,
+            >("delete_snapshot", &value.args)?
+            .into()),
+            "disband_formation" => Ok(__sdk::parse_reducer_args::<
+                disband_formation_reducer::DisbandFormationArgs,
+            >("disband_formation", &value.args)?
+            .into()),
+            "execute_maneuvers" => Ok(__sdk::parse_reducer_args::<
+                execute_maneuvers_reducer::ExecuteManeuversArgs,
+            >("execute_maneuvers", &value.args)?
+            .into()),
+            "export_world" => Ok(
+                __sdk::parse_reducer_args::<export_world_reducer::ExportWorldArgs>(
+                    "export_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "grant_admin" => Ok(
+                __sdk::parse_reducer_args::<grant_admin_reducer::GrantAdminArgs>(
+                    "grant_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "import_world" => Ok(
+                __sdk::parse_reducer_args::<import_world_reducer::ImportWorldArgs>(
+                    "import_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "join_formation" => Ok(__sdk::parse_reducer_args::<
+                join_formation_reducer::JoinFormationArgs,
+            >("join_formation", &value.args)?
+            .into()),
+            "leave_formation" => Ok(__sdk::parse_reducer_args::<
+                leave_formation_reducer::LeaveFormationArgs,
+            >("leave_formation", &value.args)?
+            .into()),
+            "load_scenario" => Ok(__sdk::parse_reducer_args::<
+                load_scenario_reducer::LoadScenarioArgs,
+            >("load_scenario", &value.args)?
+            .into()),
+            "migrate" => Ok(__sdk::parse_reducer_args::<migrate_reducer::MigrateArgs>(
+                "migrate",
+                &value.args,
+            )?
+            .into()),
+            "move_node" => Ok(
+                __sdk::parse_reducer_args::<move_node_reducer::MoveNodeArgs>(
+                    "move_node",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "pilot_input" => Ok(
+                __sdk::parse_reducer_args::<pilot_input_reducer::PilotInputArgs>(
+                    "pilot_input",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "plan_maneuver" => Ok(__sdk::parse_reducer_args::<
+                plan_maneuver_reducer::PlanManeuverArgs,
+            >("plan_maneuver", &value.args)?
+            .into()),
+            "plan_transfer" => Ok(__sdk::parse_reducer_args::<
+                plan_transfer_reducer::PlanTransferArgs,
+            >("plan_transfer", &value.args)?
+            .into()),
+            "release_control" => Ok(__sdk::parse_reducer_args::<
+                release_control_reducer::ReleaseControlArgs,
+            >("release_control", &value.args)?
+            .into()),
+            "request_path_prediction" => Ok(__sdk::parse_reducer_args::<
+                request_path_prediction_reducer::RequestPathPredictionArgs,
+            >("request_path_prediction", &value.args)?
+            .into()),
+            "reset_ship" => Ok(
+                __sdk::parse_reducer_args::<reset_ship_reducer::ResetShipArgs>(
+                    "reset_ship",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "restore_world" => Ok(__sdk::parse_reducer_args::<
+                restore_world_reducer::RestoreWorldArgs,
+            >("restore_world", &value.args)?
+            .into()),
+            "set_integrator" => Ok(__sdk::parse_reducer_args::<
+                set_integrator_reducer::SetIntegratorArgs,
+            >("set_integrator", &value.args)?
+            .into()),
+            "spawn_ship" => Ok(
+                __sdk::parse_reducer_args::<spawn_ship_reducer::SpawnShipArgs>(
+                    "spawn_ship",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "take_control" => Ok(
+                __sdk::parse_reducer_args::<take_control_reducer::TakeControlArgs>(
+                    "take_control",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "test_reducer" => Ok(
                 __sdk::parse_reducer_args::<test_reducer_reducer::TestReducerArgs>(
                     "test_reducer",
@@ -83,11 +507,26 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
     animation_counter: __sdk::TableUpdate<AnimationCounter>,
     entity: __sdk::TableUpdate<Entity>,
+    entity_cell: __sdk::TableUpdate<EntityCell>,
+    formation: __sdk::TableUpdate<Formation>,
+    formation_member: __sdk::TableUpdate<FormationMember>,
+    maneuver: __sdk::TableUpdate<Maneuver>,
+    maneuver_schedule: __sdk::TableUpdate<ManeuverSchedule>,
     node: __sdk::TableUpdate<Node>,
+    path_prediction: __sdk::TableUpdate<PathPrediction>,
+    pilot: __sdk::TableUpdate<Pilot>,
+    pilot_input: __sdk::TableUpdate<PilotInput>,
+    predicted_path: __sdk::TableUpdate<PredictedPath>,
+    schema_version: __sdk::TableUpdate<SchemaVersion>,
+    ship_class: __sdk::TableUpdate<ShipClass>,
     test_reducer_schedule: __sdk::TableUpdate<TestReducerSchedule>,
+    transfer_plan: __sdk::TableUpdate<TransferPlan>,
     waypoint: __sdk::TableUpdate<Waypoint>,
+    world_settings: __sdk::TableUpdate<WorldSettings>,
+    world_snapshot: __sdk::TableUpdate<WorldSnapshotRow>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -96,19 +535,67 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update.admin = admin_table::parse_table_update(table_update)?,
                 "animation_counter" => {
                     db_update.animation_counter =
                         animation_counter_table::parse_table_update(table_update)?
                 }
                 "entity" => db_update.entity = entity_table::parse_table_update(table_update)?,
+                "entity_cell" => {
+                    db_update.entity_cell = entity_cell_table::parse_table_update(table_update)?
+                }
+                "formation" => {
+                    db_update.formation = formation_table::parse_table_update(table_update)?
+                }
+                "formation_member" => {
+                    db_update.formation_member =
+                        formation_member_table::parse_table_update(table_update)?
+                }
+                "maneuver" => {
+                    db_update.maneuver = maneuver_table::parse_table_update(table_update)?
+                }
+                "maneuver_schedule" => {
+                    db_update.maneuver_schedule =
+                        maneuver_schedule_table::parse_table_update(table_update)?
+                }
                 "node" => db_update.node = node_table::parse_table_update(table_update)?,
+                "path_prediction" => {
+                    db_update.path_prediction =
+                        path_prediction_table::parse_table_update(table_update)?
+                }
+                "pilot" => db_update.pilot = pilot_table::parse_table_update(table_update)?,
+                "pilot_input" => {
+                    db_update.pilot_input = pilot_input_table::parse_table_update(table_update)?
+                }
+                "predicted_path" => {
+                    db_update.predicted_path =
+                        predicted_path_table::parse_table_update(table_update)?
+                }
+                "schema_version" => {
+                    db_update.schema_version =
+                        schema_version_table::parse_table_update(table_update)?
+                }
+                "ship_class" => {
+                    db_update.ship_class = ship_class_table::parse_table_update(table_update)?
+                }
                 "test_reducer_schedule" => {
                     db_update.test_reducer_schedule =
                         test_reducer_schedule_table::parse_table_update(table_update)?
                 }
+                "transfer_plan" => {
+                    db_update.transfer_plan = transfer_plan_table::parse_table_update(table_update)?
+                }
                 "waypoint" => {
                     db_update.waypoint = waypoint_table::parse_table_update(table_update)?
                 }
+                "world_settings" => {
+                    db_update.world_settings =
+                        world_settings_table::parse_table_update(table_update)?
+                }
+                "world_snapshot" => {
+                    db_update.world_snapshot =
+                        world_snapshot_table::parse_table_update(table_update)?
+                }
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -135,24 +622,69 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
         diff.animation_counter = cache
             .apply_diff_to_table::<AnimationCounter>("animation_counter", &self.animation_counter)
             .with_updates_by_pk(|row| &row.id);
         diff.entity = cache
             .apply_diff_to_table::<Entity>("entity", &self.entity)
             .with_updates_by_pk(|row| &row.id);
+        diff.entity_cell = cache
+            .apply_diff_to_table::<EntityCell>("entity_cell", &self.entity_cell)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.formation = cache
+            .apply_diff_to_table::<Formation>("formation", &self.formation)
+            .with_updates_by_pk(|row| &row.id);
+        diff.formation_member = cache
+            .apply_diff_to_table::<FormationMember>("formation_member", &self.formation_member)
+            .with_updates_by_pk(|row| &row.id);
+        diff.maneuver = cache
+            .apply_diff_to_table::<Maneuver>("maneuver", &self.maneuver)
+            .with_updates_by_pk(|row| &row.id);
+        diff.maneuver_schedule = cache
+            .apply_diff_to_table::<ManeuverSchedule>("maneuver_schedule", &self.maneuver_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.node = cache
             .apply_diff_to_table::<Node>("node", &self.node)
             .with_updates_by_pk(|row| &row.id);
+        diff.path_prediction = cache
+            .apply_diff_to_table::<PathPrediction>("path_prediction", &self.path_prediction)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.pilot = cache
+            .apply_diff_to_table::<Pilot>("pilot", &self.pilot)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.pilot_input = cache
+            .apply_diff_to_table::<PilotInput>("pilot_input", &self.pilot_input)
+            .with_updates_by_pk(|row| &row.id);
+        diff.predicted_path = cache
+            .apply_diff_to_table::<PredictedPath>("predicted_path", &self.predicted_path)
+            .with_updates_by_pk(|row| &row.id);
+        diff.schema_version = cache
+            .apply_diff_to_table::<SchemaVersion>("schema_version", &self.schema_version)
+            .with_updates_by_pk(|row| &row.id);
+        diff.ship_class = cache
+            .apply_diff_to_table::<ShipClass>("ship_class", &self.ship_class)
+            .with_updates_by_pk(|row| &row.id);
         diff.test_reducer_schedule = cache
             .apply_diff_to_table::<TestReducerSchedule>(
                 "test_reducer_schedule",
                 &self.test_reducer_schedule,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.transfer_plan = cache
+            .apply_diff_to_table::<TransferPlan>("transfer_plan", &self.transfer_plan)
+            .with_updates_by_pk(|row| &row.id);
         diff.waypoint = cache
             .apply_diff_to_table::<Waypoint>("waypoint", &self.waypoint)
             .with_updates_by_pk(|row| &row.id);
+        diff.world_settings = cache
+            .apply_diff_to_table::<WorldSettings>("world_settings", &self.world_settings)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_snapshot = cache
+            .apply_diff_to_table::<WorldSnapshotRow>("world_snapshot", &self.world_snapshot)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -162,11 +694,26 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
     animation_counter: __sdk::TableAppliedDiff<'r, AnimationCounter>,
     entity: __sdk::TableAppliedDiff<'r, Entity>,
+    entity_cell: __sdk::TableAppliedDiff<'r, EntityCell>,
+    formation: __sdk::TableAppliedDiff<'r, Formation>,
+    formation_member: __sdk::TableAppliedDiff<'r, FormationMember>,
+    maneuver: __sdk::TableAppliedDiff<'r, Maneuver>,
+    maneuver_schedule: __sdk::TableAppliedDiff<'r, ManeuverSchedule>,
     node: __sdk::TableAppliedDiff<'r, Node>,
+    path_prediction: __sdk::TableAppliedDiff<'r, PathPrediction>,
+    pilot: __sdk::TableAppliedDiff<'r, Pilot>,
+    pilot_input: __sdk::TableAppliedDiff<'r, PilotInput>,
+    predicted_path: __sdk::TableAppliedDiff<'r, PredictedPath>,
+    schema_version: __sdk::TableAppliedDiff<'r, SchemaVersion>,
+    ship_class: __sdk::TableAppliedDiff<'r, ShipClass>,
     test_reducer_schedule: __sdk::TableAppliedDiff<'r, TestReducerSchedule>,
+    transfer_plan: __sdk::TableAppliedDiff<'r, TransferPlan>,
     waypoint: __sdk::TableAppliedDiff<'r, Waypoint>,
+    world_settings: __sdk::TableAppliedDiff<'r, WorldSettings>,
+    world_snapshot: __sdk::TableAppliedDiff<'r, WorldSnapshotRow>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -179,19 +726,66 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
         callbacks.invoke_table_row_callbacks::<AnimationCounter>(
             "animation_counter",
             &self.animation_counter,
             event,
         );
         callbacks.invoke_table_row_callbacks::<Entity>("entity", &self.entity, event);
+        callbacks.invoke_table_row_callbacks::<EntityCell>("entity_cell", &self.entity_cell, event);
+        callbacks.invoke_table_row_callbacks::<Formation>("formation", &self.formation, event);
+        callbacks.invoke_table_row_callbacks::<FormationMember>(
+            "formation_member",
+            &self.formation_member,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Maneuver>("maneuver", &self.maneuver, event);
+        callbacks.invoke_table_row_callbacks::<ManeuverSchedule>(
+            "maneuver_schedule",
+            &self.maneuver_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Node>("node", &self.node, event);
+        callbacks.invoke_table_row_callbacks::<PathPrediction>(
+            "path_prediction",
+            &self.path_prediction,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Pilot>("pilot", &self.pilot, event);
+        callbacks.invoke_table_row_callbacks::<PilotInput>("pilot_input", &self.pilot_input, event);
+        callbacks.invoke_table_row_callbacks::<PredictedPath>(
+            "predicted_path",
+            &self.predicted_path,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SchemaVersion>(
+            "schema_version",
+            &self.schema_version,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ShipClass>("ship_class", &self.ship_class, event);
         callbacks.invoke_table_row_callbacks::<TestReducerSchedule>(
             "test_reducer_schedule",
             &self.test_reducer_schedule,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<TransferPlan>(
+            "transfer_plan",
+            &self.transfer_plan,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Waypoint>("waypoint", &self.waypoint, event);
+        callbacks.invoke_table_row_callbacks::<WorldSettings>(
+            "world_settings",
+            &self.world_settings,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WorldSnapshotRow>(
+            "world_snapshot",
+            &self.world_snapshot,
+            event,
+        );
     }
 }
 
@@ -767,10 +1361,25 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
         animation_counter_table::register_table(client_cache);
         entity_table::register_table(client_cache);
+        entity_cell_table::register_table(client_cache);
+        formation_table::register_table(client_cache);
+        formation_member_table::register_table(client_cache);
+        maneuver_table::register_table(client_cache);
+        maneuver_schedule_table::register_table(client_cache);
         node_table::register_table(client_cache);
+        path_prediction_table::register_table(client_cache);
+        pilot_table::register_table(client_cache);
+        pilot_input_table::register_table(client_cache);
+        predicted_path_table::register_table(client_cache);
+        schema_version_table::register_table(client_cache);
+        ship_class_table::register_table(client_cache);
         test_reducer_schedule_table::register_table(client_cache);
+        transfer_plan_table::register_table(client_cache);
         waypoint_table::register_table(client_cache);
+        world_settings_table::register_table(client_cache);
+        world_snapshot_table::register_table(client_cache);
     }
 }
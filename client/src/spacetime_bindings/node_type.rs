@@ -19,6 +19,8 @@ pub struct Node {
     pub node_rotation: DQuat,
     pub node_rotational_velocity: DVec3,
     pub orbit_progress: f64,
+    pub mass: f64,
+    pub radius: f64,
 }
 
 impl __sdk::InModule for Node {
@@ -0,0 +1,282 @@
+//! Reducer calls as Bevy events.
+//!
+//! Every reducer a player or admin can call has a command event, e.g.
+//! `EventWriter<ResetShip>`. A system per command forwards them to the live
+//! connection, and how each call went comes back as a
+//! `CommandOutcome<ResetShip>`, so callers never touch the SDK.
+
+use bevy::diagnostic::Diagnostics;
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Identity, Status, Timestamp};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant, SystemTime};
+
+use shared::math::DVec3;
+use shared::trajectory::Integrator;
+use shared::NodeName;
+
+use super::connection::{ConnectedEvent, ServerConnection};
+use super::diagnostics::REDUCER_ROUND_TRIP;
+use crate::spacetime_bindings::{self as db, ReducerEventContext, RemoteReducers};
+pub use crate::spacetime_bindings::{ManeuverFrame, TransferMethod};
+
+/// A reducer call
+pub trait Command: Event + Clone + Debug {
+  /// The reducer it calls, for logs
+  const REDUCER: &'static str;
+
+  fn call(self, reducers: &RemoteReducers) -> spacetimedb_sdk::Result<()>;
+
+  /// Hand every finished call of the reducer to `callback`, as the command that made it
+  fn on_outcome(reducers: &RemoteReducers, callback: impl FnMut(&ReducerEventContext, Self) + Send + 'static);
+}
+
+/// How a command went
+#[derive(Event, Clone, Debug)]
+#[allow(dead_code)]
+pub struct CommandOutcome<C: Command> {
+  pub command: C,
+  /// The reducer's error, or why the call never reached the server
+  pub result: Result<(), String>,
+  /// From sending the call to hearing back, if it was sent
+  pub round_trip: Option<Duration>,
+}
+
+/// When each unanswered call of a command was sent. The server answers a
+/// client's calls in order, so the oldest one is answered next.
+#[derive(Resource)]
+struct InFlight<C: Command> {
+  sent: VecDeque<Instant>,
+  command: PhantomData<fn() -> C>,
+}
+
+impl<C: Command> Default for InFlight<C> {
+  fn default() -> Self {
+    Self {
+      sent: VecDeque::new(),
+      command: PhantomData,
+    }
+  }
+}
+
+/// Turns a command field into a reducer argument and back. Fields the
+/// bindings take as they are go through the identity impl.
+trait Arg<T> {
+  fn arg(self) -> T;
+}
+
+impl<T> Arg<T> for T {
+  fn arg(self) -> T {
+    self
+  }
+}
+
+macro_rules! arg_conversions {
+  ($($ours:ty => $theirs:ty),* $(,)?) => {
+    $(
+      impl Arg<$theirs> for $ours {
+        fn arg(self) -> $theirs {
+          self.into()
+        }
+      }
+
+      impl Arg<$ours> for $theirs {
+        fn arg(self) -> $ours {
+          self.into()
+        }
+      }
+    )*
+  };
+}
+
+arg_conversions! {
+  DVec3 => db::DVec3,
+  NodeName => db::NodeName,
+  Integrator => db::Integrator,
+  SystemTime => Timestamp,
+}
+
+impl Arg<Option<db::NodeName>> for Option<NodeName> {
+  fn arg(self) -> Option<db::NodeName> {
+    self.map(Into::into)
+  }
+}
+
+impl Arg<Option<NodeName>> for Option<db::NodeName> {
+  fn arg(self) -> Option<NodeName> {
+    self.map(Into::into)
+  }
+}
+
+macro_rules! command {
+  // Reducers without arguments become unit structs
+  (@struct $(#[$doc:meta])* $command:ident {}) => {
+    $(#[$doc])*
+    #[derive(Event, Clone, Debug)]
+    #[allow(dead_code)] // Not every command has a sender yet
+    pub struct $command;
+  };
+  (@struct $(#[$doc:meta])* $command:ident { $($field:ident: $ty:ty),+ }) => {
+    $(#[$doc])*
+    #[derive(Event, Clone, Debug)]
+    #[allow(dead_code)] // Not every command has a sender yet
+    pub struct $command {
+      $(pub $field: $ty),+
+    }
+  };
+  ($(#[$doc:meta])* $command:ident { $($field:ident: $ty:ty),* } => $reducer:ident, $on_reducer:ident) => {
+    command!(@struct $(#[$doc])* $command { $($field: $ty),* });
+
+    impl Command for $command {
+      const REDUCER: &'static str = stringify!($reducer);
+
+      fn call(self, reducers: &RemoteReducers) -> spacetimedb_sdk::Result<()> {
+        use db::$reducer as _;
+        let $command { $($field),* } = self;
+        reducers.$reducer($($field.arg()),*)
+      }
+
+      fn on_outcome(reducers: &RemoteReducers, mut callback: impl FnMut(&ReducerEventContext, Self) + Send + 'static) {
+        use db::$reducer as _;
+        reducers.$on_reducer(move |ctx $(, $field)*| {
+          callback(ctx, $command { $($field: Clone::clone($field).arg()),* })
+        });
+      }
+    }
+  };
+}
+
+macro_rules! commands {
+  ($($(#[$doc:meta])* $command:ident { $($field:ident: $ty:ty),* $(,)? } => $reducer:ident, $on_reducer:ident;)*) => {
+    $(command!($(#[$doc])* $command { $($field: $ty),* } => $reducer, $on_reducer);)*
+
+    /// Every command's events and forwarding
+    pub fn commands_plugin(app: &mut App) {
+      $(command_plugin::<$command>(app);)*
+    }
+  };
+}
+
+// The scheduled reducers (`test_reducer`, `execute_maneuvers`) only run on
+// the server's clock and have no commands. Neither has `pilot_input` - the
+// piloting system sends it every solver step and reads the acks off the
+// pilot row instead.
+commands! {
+  /// Put the test ship back where it started
+  ResetShip {} => reset_ship, on_reset_ship;
+  SpawnShip { class: String, designation: String, node: NodeName, position: DVec3 } => spawn_ship, on_spawn_ship;
+
+  /// Fly a ship by hand
+  TakeControl { entity_id: u64 } => take_control, on_take_control;
+  ReleaseControl { entity_id: u64 } => release_control, on_release_control;
+
+  /// Queue a waypoint, in the frame of the ship's node
+  AddWaypoint { entity_id: u64, target_position: DVec3 } => add_waypoint, on_add_waypoint;
+  PlanManeuver { entity_id: u64, execute_at: SystemTime, delta_v: DVec3, frame: ManeuverFrame } => plan_maneuver, on_plan_maneuver;
+  CancelManeuver { maneuver_id: u64 } => cancel_maneuver, on_cancel_maneuver;
+  /// Plan the burns to another node, the server picks the method if none is given
+  PlanTransfer { entity_id: u64, destination: NodeName, method: Option<TransferMethod> } => plan_transfer, on_plan_transfer;
+  RequestPathPrediction { entity_id: u64, horizon: f64, resolution: f64 } => request_path_prediction, on_request_path_prediction;
+  CancelPathPrediction { entity_id: u64 } => cancel_path_prediction, on_cancel_path_prediction;
+
+  CreateFormation { name: String, leader_id: u64 } => create_formation, on_create_formation;
+  JoinFormation { name: String, entity_id: u64, offset: DVec3 } => join_formation, on_join_formation;
+  LeaveFormation { entity_id: u64 } => leave_formation, on_leave_formation;
+  DisbandFormation { name: String } => disband_formation, on_disband_formation;
+
+  CreateNode { name: String, parent: Option<NodeName>, position: DVec3, velocity: DVec3, mass: f64, radius: f64 } => create_node, on_create_node;
+  MoveNode { name: String, parent: Option<NodeName>, position: DVec3, velocity: DVec3 } => move_node, on_move_node;
+  DeleteNode { name: String } => delete_node, on_delete_node;
+
+  LoadScenario { name: String } => load_scenario, on_load_scenario;
+  SetIntegrator { integrator: Integrator } => set_integrator, on_set_integrator;
+  ExportWorld { name: String } => export_world, on_export_world;
+  ImportWorld { document: String } => import_world, on_import_world;
+  RestoreWorld { name: String } => restore_world, on_restore_world;
+  DeleteSnapshot { name: String } => delete_snapshot, on_delete_snapshot;
+  Migrate {} => migrate, on_migrate;
+  GrantAdmin { identity: Identity } => grant_admin, on_grant_admin;
+}
+
+fn command_plugin<C: Command>(app: &mut App) {
+  app.add_event::<C>()
+    .add_event::<CommandOutcome<C>>()
+    .init_resource::<InFlight<C>>()
+    .add_systems(Update, (observe_outcomes_system::<C>, forward_commands_system::<C>, record_round_trip_system::<C>).chain());
+}
+
+fn status_result(status: &Status) -> Result<(), String> {
+  match status {
+    Status::Committed => Ok(()),
+    Status::Failed(message) => Err(message.to_string()),
+    Status::OutOfEnergy => Err("Out of energy".to_string()),
+  }
+}
+
+/// Report our own calls of the reducer back as events, on every new connection
+fn observe_outcomes_system<C: Command>(
+  server: Res<ServerConnection>,
+  mut connected: EventReader<ConnectedEvent>,
+  mut in_flight: ResMut<InFlight<C>>,
+) {
+  if connected.read().count() == 0 {
+    return;
+  }
+  // Calls on the previous connection will never be answered
+  in_flight.sent.clear();
+  let Some(conn) = server.get() else {
+    return;
+  };
+  let tx = server.sender();
+  C::on_outcome(&conn.reducers, move |ctx, command| {
+    // Other clients' calls show up too when they touch our rows
+    if ctx.event.caller_connection_id != Some(ctx.connection_id()) {
+      return;
+    }
+    let result = status_result(&ctx.event.status);
+    if let Err(err) = &result {
+      warn!("{} failed: {}", C::REDUCER, err);
+    }
+    let _ = tx.send(Box::new(move |world: &mut World| {
+      let round_trip = world.resource_mut::<InFlight<C>>().sent.pop_front().map(|sent| sent.elapsed());
+      world.send_event(CommandOutcome { command, result, round_trip });
+    }));
+  });
+}
+
+/// Send the frame's commands. Failures to send are reported right away,
+/// everything else once the server ran it.
+fn forward_commands_system<C: Command>(
+  server: Res<ServerConnection>,
+  mut commands: EventReader<C>,
+  mut outcomes: EventWriter<CommandOutcome<C>>,
+  mut in_flight: ResMut<InFlight<C>>,
+) {
+  for command in commands.read() {
+    let sent = match server.get() {
+      Some(conn) => command.clone().call(&conn.reducers).map_err(|err| err.to_string()),
+      None => Err("Not connected".to_string()),
+    };
+    match sent {
+      Ok(()) => {
+        debug!("Called {}: {:?}", C::REDUCER, command);
+        in_flight.sent.push_back(Instant::now());
+      },
+      Err(err) => {
+        warn!("Failed to call {}: {}", C::REDUCER, err);
+        outcomes.write(CommandOutcome { command: command.clone(), result: Err(err), round_trip: None });
+      },
+    }
+  }
+}
+
+fn record_round_trip_system<C: Command>(
+  mut outcomes: EventReader<CommandOutcome<C>>,
+  mut diagnostics: Diagnostics,
+) {
+  for round_trip in outcomes.read().filter_map(|outcome| outcome.round_trip) {
+    diagnostics.add_measurement(&REDUCER_ROUND_TRIP, || round_trip.as_secs_f64() * 1000.0);
+  }
+}
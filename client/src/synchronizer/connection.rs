@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table, TableWithPrimaryKey};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::spacetime_bindings::{
+  DbConnection, Entity as DbEntity, EntityTableAccess, EventContext, Node as DbNode, NodeTableAccess,
+  Pilot, PilotTableAccess, Waypoint, WaypointTableAccess,
+};
+use super::connection_settings::ConnectionSettings;
+
+/// First retry delay, doubled on every failed attempt
+const RETRY_BASE: Duration = Duration::from_secs(1);
+/// Longest wait between two attempts
+const RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// A row was inserted into a mirrored table
+#[derive(Event)]
+pub struct InsertEvent<T: Send + Sync + 'static> {
+  pub row: T,
+}
+
+/// A row of a mirrored table changed, carrying the new version
+#[derive(Event)]
+pub struct UpdateEvent<T: Send + Sync + 'static> {
+  pub new: T,
+}
+
+/// A row was deleted from a mirrored table
+#[derive(Event)]
+pub struct DeleteEvent<T: Send + Sync + 'static> {
+  pub row: T,
+}
+
+/// A row left the subscribed queries without being deleted on the server,
+/// e.g. an entity outside the area of interest after the queries moved
+#[derive(Event)]
+pub struct OutOfScopeEvent<T: Send + Sync + 'static> {
+  pub row: T,
+}
+
+/// Sent on every successful connect, first and reconnects alike. Mirrored
+/// state from an earlier connection is stale by then and gets rebuilt.
+#[derive(Event)]
+pub struct ConnectedEvent;
+
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub enum ConnectionStatus {
+  Connecting { attempt: u32 },
+  Online,
+  Offline { reason: String, retry_in: Duration },
+}
+
+/// Work queued by SDK callbacks on the connection thread, run on the main thread
+pub(super) type Message = Box<dyn FnOnce(&mut World) + Send>;
+
+/// The live connection, rebuilt with backoff whenever it drops
+#[derive(Resource)]
+pub struct ServerConnection {
+  conn: Option<DbConnection>,
+  sender: Sender<Message>,
+  receiver: Mutex<Receiver<Message>>,
+  /// An attempt is being built on a background thread
+  connecting: bool,
+  attempt: u32,
+  retry: Timer,
+}
+
+impl ServerConnection {
+  fn new() -> Self {
+    let (sender, receiver) = channel();
+    Self {
+      conn: None,
+      sender,
+      receiver: Mutex::new(receiver),
+      connecting: false,
+      attempt: 0,
+      retry: Timer::new(Duration::ZERO, TimerMode::Once),
+    }
+  }
+
+  /// The connection, while online
+  pub fn get(&self) -> Option<&DbConnection> {
+    self.conn.as_ref().filter(|conn| conn.is_active())
+  }
+
+  /// Queue work for the main thread, for SDK callbacks registered elsewhere
+  pub(super) fn sender(&self) -> Sender<Message> {
+    self.sender.clone()
+  }
+
+  fn retry_delay(&self) -> Duration {
+    RETRY_BASE.saturating_mul(1 << self.attempt.min(16)).min(RETRY_MAX)
+  }
+
+  /// Drop the connection and wait before the next attempt
+  fn go_offline(&mut self, reason: String, status: &mut ConnectionStatus) {
+    if let Some(conn) = self.conn.take() {
+      let _ = conn.disconnect();
+    }
+    let retry_in = self.retry_delay();
+    self.attempt += 1;
+    self.retry = Timer::new(retry_in, TimerMode::Once);
+    warn!("Offline ({}), retrying in {:.0?}", reason, retry_in);
+    *status = ConnectionStatus::Offline { reason, retry_in };
+  }
+}
+
+pub fn connection_plugin(app: &mut App) {
+  app.insert_resource(ServerConnection::new())
+    .insert_resource(ConnectionStatus::Connecting { attempt: 0 })
+    .add_event::<ConnectedEvent>()
+    .add_systems(PreUpdate, (reconnect_system, apply_messages_system).chain());
+
+  add_table_events::<DbEntity>(app);
+  add_table_events::<DbNode>(app);
+  add_table_events::<Pilot>(app);
+  add_table_events::<Waypoint>(app);
+}
+
+fn add_table_events<T: Send + Sync + 'static>(app: &mut App) {
+  app.add_event::<InsertEvent<T>>()
+    .add_event::<UpdateEvent<T>>()
+    .add_event::<DeleteEvent<T>>()
+    .add_event::<OutOfScopeEvent<T>>();
+}
+
+/// Forward a table's row callbacks into Bevy events
+fn mirror<T: Clone + Send + Sync + 'static>(
+  table: impl TableWithPrimaryKey<Row = T, EventContext = EventContext>,
+  sender: &Sender<Message>,
+) {
+  let tx = sender.clone();
+  table.on_insert(move |_, row| {
+    let row = row.clone();
+    let _ = tx.send(Box::new(move |world: &mut World| {
+      world.send_event(InsertEvent { row });
+    }));
+  });
+  let tx = sender.clone();
+  table.on_update(move |_, _, new| {
+    let new = new.clone();
+    let _ = tx.send(Box::new(move |world: &mut World| {
+      world.send_event(UpdateEvent { new });
+    }));
+  });
+  let tx = sender.clone();
+  table.on_delete(move |ctx, row| {
+    let row = row.clone();
+    // Dropping a query takes its rows away, they still exist on the server
+    let out_of_scope = matches!(ctx.event, spacetimedb_sdk::Event::UnsubscribeApplied);
+    let _ = tx.send(Box::new(move |world: &mut World| {
+      if out_of_scope {
+        world.send_event(OutOfScopeEvent { row });
+      } else {
+        world.send_event(DeleteEvent { row });
+      }
+    }));
+  });
+}
+
+fn connect(settings: &ConnectionSettings, sender: &Sender<Message>) -> Result<DbConnection, String> {
+  let on_connect = sender.clone();
+  let on_connect_error = sender.clone();
+  let on_disconnect = sender.clone();
+  let token_settings = settings.clone();
+
+  let conn = DbConnection::builder()
+    .with_module_name(&settings.module)
+    .with_uri(&settings.uri)
+    .with_token(settings.load_token())
+    .on_connect(move |_ctx, _identity, token| {
+      // Reconnect as the same identity next time
+      token_settings.save_token(token);
+      let _ = on_connect.send(Box::new(|world: &mut World| {
+        let mut server = world.resource_mut::<ServerConnection>();
+        server.attempt = 0;
+        *world.resource_mut::<ConnectionStatus>() = ConnectionStatus::Online;
+        world.send_event(ConnectedEvent);
+      }));
+    })
+    .on_connect_error(move |_ctx, err| {
+      let reason = err.to_string();
+      let _ = on_connect_error.send(Box::new(move |world: &mut World| disconnected(world, reason)));
+    })
+    .on_disconnect(move |_ctx, err| {
+      let reason = err.map(|err| err.to_string()).unwrap_or_else(|| "disconnected".to_string());
+      let _ = on_disconnect.send(Box::new(move |world: &mut World| disconnected(world, reason)));
+    })
+    .build()
+    .map_err(|err| err.to_string())?;
+
+  mirror(conn.db.entity(), sender);
+  mirror(conn.db.node(), sender);
+  mirror(conn.db.pilot(), sender);
+  mirror(conn.db.waypoint(), sender);
+  Ok(conn)
+}
+
+fn disconnected(world: &mut World, reason: String) {
+  // A failed build already went offline, its error callback must not count twice
+  if matches!(*world.resource::<ConnectionStatus>(), ConnectionStatus::Offline { .. }) {
+    return;
+  }
+  world.resource_scope(|world, mut server: Mut<ServerConnection>| {
+    let mut status = world.resource_mut::<ConnectionStatus>();
+    server.go_offline(reason, &mut status);
+  });
+}
+
+/// Connect on startup, and again once the backoff after a drop has passed
+fn reconnect_system(
+  time: Res<Time>,
+  settings: Res<ConnectionSettings>,
+  mut server: ResMut<ServerConnection>,
+  mut status: ResMut<ConnectionStatus>,
+) {
+  if server.conn.is_some() || server.connecting {
+    return;
+  }
+  server.retry.tick(time.delta());
+  if !server.retry.finished() {
+    return;
+  }
+
+  *status = ConnectionStatus::Connecting { attempt: server.attempt };
+  info!("Connecting to module '{}' at {} (attempt {})", settings.module, settings.uri, server.attempt + 1);
+  server.connecting = true;
+
+  // Building blocks until the server answers, or the attempt times out
+  let settings = settings.clone();
+  let sender = server.sender.clone();
+  std::thread::spawn(move || {
+    let result = connect(&settings, &sender);
+    let _ = sender.send(Box::new(move |world: &mut World| {
+      world.resource_scope(|world, mut server: Mut<ServerConnection>| {
+        server.connecting = false;
+        match result {
+          Ok(conn) => {
+            // Only now, so `on_connect` finds the connection in place
+            conn.run_threaded();
+            server.conn = Some(conn);
+          },
+          Err(reason) => server.go_offline(reason, &mut world.resource_mut::<ConnectionStatus>()),
+        }
+      });
+    }));
+  });
+}
+
+/// Run everything the connection thread queued since the last frame
+fn apply_messages_system(world: &mut World) {
+  let messages: Vec<Message> = {
+    let server = world.resource::<ServerConnection>();
+    let receiver = server.receiver.lock().unwrap();
+    receiver.try_iter().collect()
+  };
+  for message in messages {
+    message(world);
+  }
+}
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use spacetimedb_sdk::credentials;
+use std::path::PathBuf;
+
+const DEFAULT_URI: &str = "http://localhost:3000";
+const DEFAULT_MODULE: &str = "test-reducer";
+const DEFAULT_CONFIG: &str = "connection.ron";
+
+const URI_ENV: &str = "STDB_URI";
+const MODULE_ENV: &str = "STDB_MODULE";
+const SHIP_ENV: &str = "STDB_SHIP";
+const CONFIG_ENV: &str = "STDB_CONFIG";
+
+/// Where the client connects to. Resolved once at startup from command-line
+/// arguments, then environment variables, then the config file.
+#[derive(Resource, Clone, Debug)]
+pub struct ConnectionSettings {
+  pub uri: String,
+  pub module: String,
+  /// Designation of the ship this client flies, if any
+  pub ship: Option<String>,
+}
+
+/// Optional `connection.ron`, e.g. `(uri: "http://localhost:3100")`
+#[derive(Deserialize, Default)]
+struct ConfigFile {
+  #[serde(default)]
+  uri: Option<String>,
+  #[serde(default)]
+  module: Option<String>,
+  #[serde(default)]
+  ship: Option<String>,
+}
+
+/// Values given as `--uri <uri>`, `--module <name>`, `--ship <designation>` and `--config <path>`
+#[derive(Default)]
+struct Arguments {
+  uri: Option<String>,
+  module: Option<String>,
+  ship: Option<String>,
+  config: Option<String>,
+}
+
+impl Arguments {
+  fn parse(args: impl IntoIterator<Item = String>) -> Self {
+    let mut parsed = Arguments::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      let (flag, inline) = match arg.split_once('=') {
+        Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+        None => (arg, None),
+      };
+      let slot = match flag.as_str() {
+        "--uri" => &mut parsed.uri,
+        "--module" => &mut parsed.module,
+        "--ship" => &mut parsed.ship,
+        "--config" => &mut parsed.config,
+        _ => continue,
+      };
+      *slot = inline.or_else(|| args.next());
+    }
+    parsed
+  }
+}
+
+impl ConnectionSettings {
+  pub fn resolve() -> Self {
+    let args = Arguments::parse(std::env::args().skip(1));
+    let env = |key: &str| std::env::var(key).ok().filter(|value| !value.is_empty());
+
+    let config_path = args.config.clone()
+      .or_else(|| env(CONFIG_ENV))
+      .unwrap_or_else(|| DEFAULT_CONFIG.to_string());
+    let config = load_config(&config_path);
+
+    let settings = ConnectionSettings {
+      uri: args.uri.or_else(|| env(URI_ENV)).or(config.uri).unwrap_or_else(|| DEFAULT_URI.to_string()),
+      module: args.module.or_else(|| env(MODULE_ENV)).or(config.module).unwrap_or_else(|| DEFAULT_MODULE.to_string()),
+      ship: args.ship.or_else(|| env(SHIP_ENV)).or(config.ship),
+    };
+    info!("Connection settings: module '{}' at {}", settings.module, settings.uri);
+    settings
+  }
+
+  /// Token store for this server and module, so each local instance keeps its own identity
+  pub fn credentials(&self) -> credentials::File {
+    let server: String = self.uri
+      .chars()
+      .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+      .collect();
+    credentials::File::new(format!("we-whom-lives-in-space-{}-{}", server, self.module))
+  }
+
+  /// Token saved by an earlier run, if there is one
+  pub fn load_token(&self) -> Option<String> {
+    match self.credentials().load() {
+      Ok(token) => token,
+      Err(err) => {
+        warn!("Could not read the saved auth token, connecting as a new identity: {}", err);
+        None
+      },
+    }
+  }
+
+  pub fn save_token(&self, token: &str) {
+    if let Err(err) = self.credentials().save(token) {
+      warn!("Could not save the auth token: {}", err);
+    }
+  }
+}
+
+/// A missing file is fine, a broken one is reported and ignored
+fn load_config(path: &str) -> ConfigFile {
+  let path = PathBuf::from(path);
+  let Ok(source) = std::fs::read_to_string(&path) else {
+    return ConfigFile::default();
+  };
+  match ron::from_str(&source) {
+    Ok(config) => config,
+    Err(err) => {
+      warn!("Ignoring {}: {}", path.display(), err);
+      ConfigFile::default()
+    },
+  }
+}
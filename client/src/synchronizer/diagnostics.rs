@@ -0,0 +1,134 @@
+//! Network health as Bevy diagnostics, so it can be logged, graphed or shown
+//! in the overlay (`ui_network_diagnostics`).
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+
+use super::connection::{DeleteEvent, InsertEvent, ServerConnection, UpdateEvent};
+use super::entity::EntityMapping;
+use crate::components::Selected;
+use crate::spacetime_bindings::{
+  Entity as DbEntity, EntityTableAccess, Node as DbNode, NodeTableAccess, Pilot, PilotTableAccess,
+  ShipClassTableAccess, Waypoint, WaypointTableAccess,
+};
+
+/// Row inserts, updates and deletes applied per second, over all mirrored tables
+pub const ROW_EVENTS: DiagnosticPath = DiagnosticPath::const_new("network/row_events");
+/// Time between server updates of the selected entity, in milliseconds
+pub const SELECTED_UPDATE_INTERVAL: DiagnosticPath = DiagnosticPath::const_new("network/selected_update_interval");
+/// From sending a command to hearing how it went, in milliseconds
+pub const REDUCER_ROUND_TRIP: DiagnosticPath = DiagnosticPath::const_new("network/reducer_round_trip");
+
+/// Rows in the client cache, per table
+pub const ROWS: [(&str, DiagnosticPath); 5] = [
+  ("node", DiagnosticPath::const_new("network/rows/node")),
+  ("entity", DiagnosticPath::const_new("network/rows/entity")),
+  ("waypoint", DiagnosticPath::const_new("network/rows/waypoint")),
+  ("pilot", DiagnosticPath::const_new("network/rows/pilot")),
+  ("ship_class", DiagnosticPath::const_new("network/rows/ship_class")),
+];
+
+/// Row events seen this frame
+#[derive(Resource, Default)]
+struct RowEventCount(usize);
+
+/// When the selected entity was last updated
+#[derive(Resource, Default)]
+struct SelectedUpdate {
+  entity_id: Option<u64>,
+  last: Option<f64>,
+}
+
+pub fn diagnostics_plugin(app: &mut App) {
+  app.register_diagnostic(Diagnostic::new(ROW_EVENTS).with_suffix("/s"))
+    .register_diagnostic(Diagnostic::new(SELECTED_UPDATE_INTERVAL).with_suffix("ms"))
+    .register_diagnostic(Diagnostic::new(REDUCER_ROUND_TRIP).with_suffix("ms"));
+  for (_, path) in ROWS {
+    app.register_diagnostic(Diagnostic::new(path).with_suffix(" rows").with_smoothing_factor(0.0));
+  }
+
+  app.init_resource::<RowEventCount>()
+    .init_resource::<SelectedUpdate>()
+    .add_systems(
+      Update,
+      (
+        (
+          count_row_events_system::<DbEntity>,
+          count_row_events_system::<DbNode>,
+          count_row_events_system::<Pilot>,
+          count_row_events_system::<Waypoint>,
+        ),
+        measure_row_events_system,
+        measure_selected_update_system,
+        measure_rows_system,
+      ).chain(),
+    );
+}
+
+fn count_row_events_system<T: Send + Sync + 'static>(
+  mut inserts: EventReader<InsertEvent<T>>,
+  mut updates: EventReader<UpdateEvent<T>>,
+  mut deletes: EventReader<DeleteEvent<T>>,
+  mut count: ResMut<RowEventCount>,
+) {
+  count.0 += inserts.read().count() + updates.read().count() + deletes.read().count();
+}
+
+fn measure_row_events_system(
+  mut count: ResMut<RowEventCount>,
+  mut diagnostics: Diagnostics,
+  time: Res<Time<Real>>,
+) {
+  let delta = time.delta_secs_f64();
+  let events = std::mem::take(&mut count.0);
+  if delta > 0.0 {
+    diagnostics.add_measurement(&ROW_EVENTS, || events as f64 / delta);
+  }
+}
+
+/// Time the gaps between updates of whichever entity is selected
+fn measure_selected_update_system(
+  mut updates: EventReader<UpdateEvent<DbEntity>>,
+  mut tracked: ResMut<SelectedUpdate>,
+  mut diagnostics: Diagnostics,
+  selected: Query<Entity, With<Selected>>,
+  entity_mapping: Res<EntityMapping>,
+  time: Res<Time<Real>>,
+) {
+  let entity_id = selected.iter().next().and_then(|entity| entity_mapping.get_spacetime_id(entity));
+  if entity_id != tracked.entity_id {
+    *tracked = SelectedUpdate { entity_id, last: None };
+  }
+  let Some(entity_id) = entity_id else {
+    updates.clear();
+    return;
+  };
+
+  if updates.read().any(|event| event.new.id == entity_id) {
+    let now = time.elapsed_secs_f64();
+    if let Some(last) = tracked.last {
+      diagnostics.add_measurement(&SELECTED_UPDATE_INTERVAL, || (now - last) * 1000.0);
+    }
+    tracked.last = Some(now);
+  }
+}
+
+fn measure_rows_system(
+  server: Res<ServerConnection>,
+  mut diagnostics: Diagnostics,
+) {
+  let Some(conn) = server.get() else {
+    return;
+  };
+  let counts = [
+    conn.db.node().count(),
+    conn.db.entity().count(),
+    conn.db.waypoint().count(),
+    conn.db.pilot().count(),
+    conn.db.ship_class().count(),
+  ];
+  for ((_, path), count) in ROWS.iter().zip(counts) {
+    diagnostics.add_measurement(path, || count as f64);
+  }
+}
@@ -1,44 +1,21 @@
+use bevy::ecs::system::{SystemParam, SystemParamItem};
 use bevy::prelude::*;
-use bevy_spacetimedb::{
-  InsertEvent, UpdateEvent, DeleteEvent,
-};
-use std::collections::HashMap;
+use super::connection::{UpdateEvent, ServerConnection};
+use super::mirror::{Mapping, TableMirror};
+use super::node::{node_entity, NodeMarker};
 
 use crate::spacetime_bindings::{
-  Entity as DbEntity, EntityType, DVec3, DQuat,
+  Entity as DbEntity, DVec3, DQuat, ShipClass, ShipClassTableAccess,
 };
-use crate::components::{Ship, Mass, MaxThrust, Acceleration, RotationInterpolation};
-
-/// Resource to track mapping between SpacetimeDB entity IDs and Bevy entity IDs
-#[derive(Resource, Default)]
-pub struct EntityMapping {
-  pub spacetime_to_bevy: HashMap<u64, Entity>,
-  pub bevy_to_spacetime: HashMap<Entity, u64>,
-}
-
-impl EntityMapping {
-  pub fn insert(&mut self, spacetime_id: u64, bevy_entity: Entity) {
-    self.spacetime_to_bevy.insert(spacetime_id, bevy_entity);
-    self.bevy_to_spacetime.insert(bevy_entity, spacetime_id);
-  }
-
-  pub fn remove_by_spacetime_id(&mut self, spacetime_id: u64) -> Option<Entity> {
-    if let Some(bevy_entity) = self.spacetime_to_bevy.remove(&spacetime_id) {
-      self.bevy_to_spacetime.remove(&bevy_entity);
-      Some(bevy_entity)
-    } else {
-      None
-    }
-  }
+use shared::{EntityType, NodeName};
+use crate::components::{Ship, Mass, MaxThrust, SensorRange, Acceleration, MotionInterpolation, PlayerControlled};
 
-  pub fn get_bevy_entity(&self, spacetime_id: u64) -> Option<Entity> {
-    self.spacetime_to_bevy.get(&spacetime_id).copied()
-  }
+/// Mapping between SpacetimeDB entity IDs and Bevy entity IDs
+pub type EntityMapping = Mapping<DbEntity>;
 
-  pub fn get_spacetime_id(&self, bevy_entity: Entity) -> Option<u64> {
-    self.bevy_to_spacetime.get(&bevy_entity).copied()
-  }
-}
+/// The node whose frame an entity's position is relative to
+#[derive(Component, Clone, PartialEq)]
+pub struct EntityNode(pub NodeName);
 
 /// Convert SpacetimeDB DVec3 to Bevy Vec3
 fn dvec3_to_vec3(dvec3: &DVec3) -> Vec3 {
@@ -50,29 +27,46 @@ fn dquat_to_quat(dquat: &DQuat) -> Quat {
   Quat::from_xyzw(dquat.x as f32, dquat.y as f32, dquat.z as f32, dquat.w as f32)
 }
 
+/// Hull colour for a ship class's visual model id
+fn ship_model_color(model: &str) -> &'static str {
+  match model {
+    "shuttle" => "#d8e3f0",
+    "transport" => "#b5a27a",
+    _ => "#ffd891",
+  }
+}
+
 /// Spawn a new entity based on database data
 fn spawn_entity(
   commands: &mut Commands,
   meshes: &mut ResMut<Assets<Mesh>>,
   materials: &mut ResMut<Assets<StandardMaterial>>,
   db_entity: &DbEntity,
+  ship_class: Option<&ShipClass>,
 ) -> Entity {
   let position = dvec3_to_vec3(&db_entity.relative_position);
   let transform = Transform::from_translation(position);
 
-  let entity = match db_entity.entity_type {
+  let entity = match EntityType::from(db_entity.entity_type.clone()) {
     EntityType::Ship => {
+      // Ships built from a class take their size and look from it
+      let (width, height, length) = ship_class
+        .map(|class| (class.dimensions.x as f32, class.dimensions.y as f32, class.dimensions.z as f32))
+        .unwrap_or((1.0, 1.0, 3.0));
+      let model = ship_class.map(|class| class.model.as_str()).unwrap_or_default();
+
       // Create the main ship body
-      let main_body_mesh = meshes.add(Cuboid::new(1.0, 1.0, 3.0));
+      let main_body_mesh = meshes.add(Cuboid::new(width, height, length));
       let ship_material = materials.add(StandardMaterial {
-        base_color: Srgba::hex("#ffd891").unwrap().into(),
+        base_color: Srgba::hex(ship_model_color(model)).unwrap().into(),
         metallic: 0.25,
         perceptual_roughness: 0.25,
         ..default()
       });
       
       // Create the forward indicator (a triangular prism pointing forward)
-      let indicator_mesh = meshes.add(Cuboid::new(0.5, 0.5, 1.5));
+      let indicator_length = length / 2.0;
+      let indicator_mesh = meshes.add(Cuboid::new(width / 2.0, height / 2.0, indicator_length));
       let indicator_material = materials.add(StandardMaterial {
         base_color: Srgba::hex("#ff4444").unwrap().into(), // Red for forward direction
         metallic: 0.5,
@@ -81,22 +75,26 @@ fn spawn_entity(
       });
       
       // Spawn the main ship entity
-      let ship_entity = commands.spawn((
+      let mut ship = commands.spawn((
         Mesh3d(main_body_mesh),
         MeshMaterial3d(ship_material),
         transform,
         Ship,
-        Mass(1000.0),
-        MaxThrust(bevy::math::DVec3::new(1000.0, 1000.0, 1000.0)),
+        Mass(db_entity.mass),
+        MaxThrust(bevy::math::DVec3::new(db_entity.max_impulse.x, db_entity.max_impulse.y, db_entity.max_impulse.z)),
         Acceleration::default(),
         Name::new(format!("Ship: {}", db_entity.designation)),
-      )).with_children(|parent| {
+      ));
+      if let Some(class) = ship_class {
+        ship.insert(SensorRange(class.sensor_range));
+      }
+      let ship_entity = ship.with_children(|parent| {
         // Spawn the forward indicator as a child of the ship
         // In Bevy, forward is -Z direction, so we position the indicator at negative Z
         parent.spawn((
           Mesh3d(indicator_mesh),
           MeshMaterial3d(indicator_material),
-          Transform::from_xyz(0.0, 0.0, -2.25), // Position it at the front of the ship (negative Z is forward)
+          Transform::from_xyz(0.0, 0.0, -(length + indicator_length) / 2.0), // Position it at the front of the ship (negative Z is forward)
           Name::new("Forward Indicator"),
         ));
       }).id();
@@ -196,41 +194,67 @@ fn spawn_entity(
   entity
 }
 
-/// Update an existing entity's transform based on database data
+/// How often the server sends entity updates, measured from arrivals.
+/// This is one interval for every entity, a global approximation: it follows
+/// the busiest rows, so an entity updated less often (only when it changes,
+/// say) is still interpolated over the common interval and then
+/// dead-reckoned until its next update.
+#[derive(Resource, Default)]
+pub struct ServerUpdateTiming {
+  /// Smoothed time between update batches, 0 until two have arrived
+  pub interval: f64,
+  last_update: Option<f64>,
+}
+
+impl ServerUpdateTiming {
+  /// Gaps longer than this are pauses, not the update rate
+  const MAX_GAP: f64 = 1.0;
+  /// Weight of the newest gap in the moving average
+  const SMOOTHING: f64 = 0.1;
+
+  /// Record that a batch of updates arrived
+  pub fn observe(&mut self, current_time: f64) {
+    if let Some(last_update) = self.last_update {
+      let gap = current_time - last_update;
+      if gap > 0.0 && gap < Self::MAX_GAP {
+        self.interval = if self.interval == 0.0 {
+          gap
+        } else {
+          self.interval + (gap - self.interval) * Self::SMOOTHING
+        };
+      }
+    }
+    self.last_update = Some(current_time);
+  }
+}
+
+/// Start moving an existing entity toward its new server state
 fn update_entity_transform(
   bevy_entity: Entity,
   db_entity: &DbEntity,
-  transform_query: &mut Query<&mut Transform>,
-  interpolation_query: &mut Query<&mut RotationInterpolation>,
+  transform_query: &Query<&mut Transform>,
+  interpolation_query: &mut Query<&mut MotionInterpolation>,
   commands: &mut Commands,
-  time: &Time,
+  current_time: f64,
+  interval: f64,
 ) -> bool {
-  if let Ok(mut transform) = transform_query.get_mut(bevy_entity) {
-    let new_position = dvec3_to_vec3(&db_entity.relative_position);
-    transform.translation = new_position;
+  if let Ok(transform) = transform_query.get(bevy_entity) {
+    let interpolation = MotionInterpolation::new(
+      transform,
+      dvec3_to_vec3(&db_entity.relative_position),
+      dquat_to_quat(&db_entity.relative_rotation),
+      dvec3_to_vec3(&db_entity.relative_velocity),
+      current_time,
+      interval,
+    );
 
-    // Handle rotation interpolation
-    let new_rotation = dquat_to_quat(&db_entity.relative_rotation);
-    let current_time = time.elapsed().as_secs_f64();
-    let server_update_interval = 0.05; // 50ms = 20Hz server updates
-    
-    if let Ok(mut interpolation) = interpolation_query.get_mut(bevy_entity) {
-      // Update existing interpolation with new target
-      interpolation.start_rotation = transform.rotation;
-      interpolation.target_rotation = new_rotation;
-      interpolation.start_time = current_time;
-      interpolation.duration = server_update_interval;
+    if let Ok(mut existing) = interpolation_query.get_mut(bevy_entity) {
+      *existing = interpolation;
     } else {
-      // Add interpolation component if it doesn't exist
-      commands.entity(bevy_entity).insert(RotationInterpolation::new(
-        transform.rotation,
-        new_rotation,
-        current_time,
-        server_update_interval,
-      ));
+      commands.entity(bevy_entity).insert(interpolation);
     }
 
-    debug!("Updated entity {} position to {:?}, set up rotation interpolation", db_entity.designation, new_position);
+    debug!("Updated entity {} target to {:?} over {:.3}s", db_entity.designation, db_entity.relative_position, interval);
     true
   } else {
     warn!("Failed to update transform for entity {}: Bevy entity not found", db_entity.designation);
@@ -238,99 +262,127 @@ fn update_entity_transform(
   }
 }
 
-/// System that applies smooth rotation interpolation every frame
-pub fn rotation_interpolation_system(
-  mut query: Query<(&mut Transform, &mut RotationInterpolation)>,
+/// System that moves entities between server updates every frame
+pub fn motion_interpolation_system(
+  mut query: Query<(&mut Transform, &MotionInterpolation)>,
   time: Res<Time>,
 ) {
   let current_time = time.elapsed().as_secs_f64();
   
   for (mut transform, interpolation) in query.iter_mut() {
-    if interpolation.is_complete(current_time) {
-      // Interpolation is complete, snap to final rotation
-      transform.rotation = interpolation.target_rotation;
-    } else {
-      // Apply smooth interpolation
-      transform.rotation = interpolation.get_rotation_at_time(current_time);
-    }
+    let (translation, rotation) = interpolation.sample(current_time);
+    transform.translation = translation;
+    transform.rotation = rotation;
   }
 }
 
-pub fn sync_entities_system(
-  mut commands: Commands,
-  mut meshes: ResMut<Assets<Mesh>>,
-  mut materials: ResMut<Assets<StandardMaterial>>,
-  mut entity_mapping: ResMut<EntityMapping>,
-  mut insert_events: EventReader<InsertEvent<DbEntity>>,
+/// Measure the update rate before the updates themselves are applied
+pub fn observe_update_timing_system(
   mut update_events: EventReader<UpdateEvent<DbEntity>>,
-  mut delete_events: EventReader<DeleteEvent<DbEntity>>,
-  mut transform_query: Query<&mut Transform>,
-  mut interpolation_query: Query<&mut RotationInterpolation>,
+  mut timing: ResMut<ServerUpdateTiming>,
   time: Res<Time>,
 ) {
-  // Debug logging to see if events are received
-  let insert_count = insert_events.len();
-  let update_count = update_events.len();
-  let delete_count = delete_events.len();
-  
-  if insert_count > 0 || update_count > 0 || delete_count > 0 {
-    info!("Entity events received - Inserts: {}, Updates: {}, Deletes: {}", 
-          insert_count, update_count, delete_count);
+  if update_events.read().count() > 0 {
+    timing.observe(time.elapsed().as_secs_f64());
   }
+}
 
-  // Handle entity insertions
-  for event in insert_events.read() {
-    let db_entity = &event.row;
-    info!("Processing entity insert: {} (ID: {})", db_entity.designation, db_entity.id);
-    
-    // Check if we already have this entity (shouldn't happen, but be safe)
-    if let Some(existing_entity) = entity_mapping.get_bevy_entity(db_entity.id) {
-      warn!("Entity {} already exists as Bevy Entity {:?}, skipping insert", db_entity.id, existing_entity);
-      continue;
-    }
+/// Mirrors the `entity` table
+pub struct EntityMirror;
+
+#[derive(SystemParam)]
+pub struct EntityMirrorParams<'w, 's> {
+  server: Res<'w, ServerConnection>,
+  meshes: ResMut<'w, Assets<Mesh>>,
+  materials: ResMut<'w, Assets<StandardMaterial>>,
+  transform_query: Query<'w, 's, &'static mut Transform>,
+  node_query: Query<'w, 's, &'static mut EntityNode>,
+  interpolation_query: Query<'w, 's, &'static mut MotionInterpolation>,
+  piloted_query: Query<'w, 's, (), With<PlayerControlled>>,
+  timing: Res<'w, ServerUpdateTiming>,
+  time: Res<'w, Time>,
+}
 
-    // Spawn the new entity
-    let bevy_entity = spawn_entity(&mut commands, &mut meshes, &mut materials, db_entity);
-    
-    // Track the mapping
-    entity_mapping.insert(db_entity.id, bevy_entity);
-    
-    info!("Successfully inserted entity: {} -> Bevy Entity {:?}", db_entity.designation, bevy_entity);
+impl TableMirror for EntityMirror {
+  type Row = DbEntity;
+  type Params = EntityMirrorParams<'static, 'static>;
+
+  const TABLE: &'static str = "entity";
+
+  fn key(row: &DbEntity) -> u64 {
+    row.id
   }
 
-  // Handle entity updates
-  for event in update_events.read() {
-    let db_entity = &event.new;
-    debug!("Processing entity update: {} (ID: {})", db_entity.designation, db_entity.id);
-    
-    if let Some(bevy_entity) = entity_mapping.get_bevy_entity(db_entity.id) {
-      // Try to update the transform
-      if !update_entity_transform(bevy_entity, db_entity, &mut transform_query, &mut interpolation_query, &mut commands, &time) {
-        // Transform update failed - this could be a timing issue where the entity
-        // was just spawned and Transform component isn't ready yet
-        // We'll keep the mapping and let future updates try again
-        debug!("Transform update failed for entity {} but keeping mapping - likely timing issue", db_entity.id);
+  fn label(row: &DbEntity) -> String {
+    format!("entity {} (ID: {})", row.designation, row.id)
+  }
+
+  fn spawn(row: &DbEntity, commands: &mut Commands, params: &mut SystemParamItem<Self::Params>) -> Entity {
+    // Classes are subscribed together with entities, so the class is already cached
+    let ship_class = row.ship_class_id
+      .zip(params.server.get())
+      .and_then(|(id, conn)| conn.db.ship_class().id().find(&id));
+    let bevy_entity = spawn_entity(commands, &mut params.meshes, &mut params.materials, row, ship_class.as_ref());
+    commands.entity(bevy_entity).insert(EntityNode(row.node.clone().into()));
+    bevy_entity
+  }
+
+  fn update(row: &DbEntity, bevy_entity: Entity, commands: &mut Commands, params: &mut SystemParamItem<Self::Params>) {
+    // Entering another node's frame - positions before and after aren't
+    // comparable, so jump there instead of interpolating across frames
+    let node = EntityNode(row.node.clone().into());
+    if let Ok(mut current) = params.node_query.get_mut(bevy_entity) {
+      if *current != node {
+        info!("Entity {} moved to {}", row.designation, node.0.as_str());
+        *current = node;
+        commands.entity(bevy_entity).remove::<MotionInterpolation>();
+        if let Ok(mut transform) = params.transform_query.get_mut(bevy_entity) {
+          transform.translation = dvec3_to_vec3(&row.relative_position);
+          transform.rotation = dquat_to_quat(&row.relative_rotation);
+        }
+        return;
       }
-    } else {
-      // Entity doesn't exist in our mapping - this could happen if:
-      // 1. We missed an insert event
-      // 2. The mapping was corrupted somehow
-      // For now, just log this and don't auto-spawn to avoid duplicates
-      warn!("Received update for unknown entity {} - no mapping found. Skipping update.", db_entity.id);
+    }
+
+    // The piloted ship is drawn from the local prediction instead
+    if params.piloted_query.contains(bevy_entity) {
+      return;
+    }
+    let current_time = params.time.elapsed().as_secs_f64();
+    if !update_entity_transform(
+      bevy_entity,
+      row,
+      &params.transform_query,
+      &mut params.interpolation_query,
+      commands,
+      current_time,
+      params.timing.interval,
+    ) {
+      // The entity was just spawned and its Transform isn't there yet - the next update catches up
+      debug!("Transform update failed for entity {} - likely timing issue", row.id);
     }
   }
+}
 
-  // Handle entity deletions
-  for event in delete_events.read() {
-    let db_entity = &event.row;
-    info!("Processing entity delete: {} (ID: {})", db_entity.designation, db_entity.id);
-    
-    if let Some(bevy_entity) = entity_mapping.remove_by_spacetime_id(db_entity.id) {
-      // Despawn the Bevy entity
-      commands.entity(bevy_entity).despawn();
-      info!("Successfully deleted entity: {} (Bevy Entity {:?})", db_entity.designation, bevy_entity);
-    } else {
-      warn!("Received delete for unknown entity {}", db_entity.id);
+/// Keep every entity under the node it is positioned relative to, or at the
+/// root while that node isn't mirrored
+pub fn attach_entities_system(
+  mut commands: Commands,
+  nodes: Query<(Entity, &NodeMarker)>,
+  entities: Query<(Entity, &EntityNode, Option<&ChildOf>)>,
+) {
+  for (bevy_entity, node, child_of) in entities.iter() {
+    let parent = node_entity(&nodes, &node.0);
+    if parent == child_of.map(|child_of| child_of.parent()) {
+      continue;
+    }
+    match parent {
+      Some(parent) => {
+        commands.entity(bevy_entity).insert(ChildOf(parent));
+      },
+      None => {
+        commands.entity(bevy_entity).remove::<ChildOf>();
+      },
     }
   }
 }
@@ -0,0 +1,165 @@
+//! Subscribes to the entities around the followed ship instead of whole tables.
+//!
+//! The server files every entity under a cell of its node (`entity_cell`), so
+//! the area around a point is a range query on the cell coordinates. As the
+//! ship moves the queries are swapped: the new set is subscribed first and the
+//! old one dropped once it applied, so rows in both never blink out.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, SubscriptionHandle as _};
+
+use shared::area_of_interest::Cell;
+
+use super::connection::{ConnectedEvent, ServerConnection};
+use super::connection_settings::ConnectionSettings;
+use crate::spacetime_bindings::{DbConnection, EntityTableAccess, NodeTableAccess, SubscriptionHandle};
+
+/// Entities this far from the followed ship (metres, roughly) are subscribed
+const INTEREST_RADIUS: f64 = 50_000.0;
+
+/// Where the area of interest is centred
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Centre {
+  node_id: u64,
+  cell: Cell,
+}
+
+/// What a set of queries covers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scope {
+  /// No ship to follow - every entity
+  Everything,
+  /// Only the configured ship, until it is in the cache and has a position
+  Owned,
+  /// The configured ship and everything near it
+  Around(Centre),
+}
+
+impl Scope {
+  /// Whether queries for `self` still serve a view at `other`. Small moves
+  /// keep the queries, so the centre lags the ship by up to a quarter radius.
+  fn covers(&self, other: &Scope) -> bool {
+    match (self, other) {
+      (Scope::Around(current), Scope::Around(wanted)) => {
+        current.node_id == wanted.node_id
+          && current.cell.distance(&wanted.cell) <= Cell::span(INTEREST_RADIUS / 4.0)
+      },
+      _ => self == other,
+    }
+  }
+}
+
+#[derive(Resource, Default)]
+pub struct AreaOfInterest {
+  /// The applied subscription
+  active: Option<(SubscriptionHandle, Scope)>,
+  /// Its replacement, waiting for the server to apply it
+  pending: Option<(SubscriptionHandle, Scope)>,
+  /// The server rejected these queries, don't send them again
+  failed: Option<Scope>,
+}
+
+/// Where the configured ship is, as far as the cache knows
+fn wanted_scope(conn: &DbConnection, settings: &ConnectionSettings) -> Scope {
+  let Some(designation) = &settings.ship else {
+    return Scope::Everything;
+  };
+  let Some(ship) = conn.db.entity().designation().find(designation) else {
+    return Scope::Owned;
+  };
+  let Some(node) = conn.db.node().iter().find(|node| node.name == ship.node) else {
+    return Scope::Owned;
+  };
+  Scope::Around(Centre {
+    node_id: node.id,
+    cell: Cell::containing(ship.relative_position.into()),
+  })
+}
+
+fn queries(scope: Scope, settings: &ConnectionSettings) -> Vec<String> {
+  // Ship classes come in the same subscription so they are cached before the first entity spawns,
+  // and pilots so the piloted entity is cached before its pilot row arrives
+  let mut queries = vec![
+    "SELECT * FROM ship_class".to_string(),
+    "SELECT * FROM pilot".to_string(),
+  ];
+
+  let designation = match (scope, &settings.ship) {
+    (Scope::Everything, _) | (_, None) => {
+      queries.push("SELECT * FROM entity".to_string());
+      queries.push("SELECT * FROM waypoint".to_string());
+      return queries;
+    },
+    (_, Some(designation)) => designation.replace('\'', "''"),
+  };
+
+  queries.push(format!("SELECT * FROM entity WHERE designation = '{}'", designation));
+  queries.push(format!(
+    "SELECT waypoint.* FROM waypoint JOIN entity ON waypoint.entity_id = entity.id WHERE entity.designation = '{}'",
+    designation,
+  ));
+
+  if let Scope::Around(centre) = scope {
+    let span = Cell::span(INTEREST_RADIUS);
+    let Cell { x, y, z } = centre.cell;
+    let area = format!(
+      "entity_cell.node_id = {} AND entity_cell.x >= {} AND entity_cell.x <= {} AND entity_cell.y >= {} AND entity_cell.y <= {} AND entity_cell.z >= {} AND entity_cell.z <= {}",
+      centre.node_id, x - span, x + span, y - span, y + span, z - span, z + span,
+    );
+    queries.push(format!(
+      "SELECT entity.* FROM entity JOIN entity_cell ON entity.id = entity_cell.entity_id WHERE {}",
+      area,
+    ));
+    queries.push(format!(
+      "SELECT waypoint.* FROM waypoint JOIN entity_cell ON waypoint.entity_id = entity_cell.entity_id WHERE {}",
+      area,
+    ));
+  }
+  queries
+}
+
+/// Keep the entity and waypoint subscriptions centred on the followed ship
+pub fn area_of_interest_system(
+  settings: Res<ConnectionSettings>,
+  server: Res<ServerConnection>,
+  mut interest: ResMut<AreaOfInterest>,
+  mut connected: EventReader<ConnectedEvent>,
+) {
+  // Handles from an earlier connection died with it
+  if connected.read().count() > 0 {
+    *interest = AreaOfInterest::default();
+  }
+  let Some(conn) = server.get() else {
+    return;
+  };
+
+  if let Some((handle, scope)) = interest.pending.take() {
+    if handle.is_active() {
+      if let Some((previous, _)) = interest.active.take() {
+        if let Err(err) = previous.unsubscribe() {
+          warn!("Failed to drop the previous area of interest: {}", err);
+        }
+      }
+      debug!("Area of interest is now {:?}", scope);
+      interest.active = Some((handle, scope));
+    } else if handle.is_ended() {
+      // The error callback already logged why
+      interest.failed = Some(scope);
+    } else {
+      interest.pending = Some((handle, scope));
+      return;
+    }
+  }
+
+  let wanted = wanted_scope(conn, &settings);
+  if interest.active.as_ref().is_some_and(|(_, scope)| scope.covers(&wanted)) || interest.failed == Some(wanted) {
+    return;
+  }
+
+  info!("Subscribing to {:?}", wanted);
+  let handle = conn.subscription_builder()
+    .on_applied(|_| info!("entity updated"))
+    .on_error(|_, err| error!("Error in entity subscription: {}", err))
+    .subscribe(queries(wanted, &settings));
+  interest.pending = Some((handle, wanted));
+}
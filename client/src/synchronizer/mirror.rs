@@ -0,0 +1,192 @@
+//! Mirrors a server table into Bevy entities, one entity per row.
+//!
+//! A table is mirrored by implementing [`TableMirror`] for a marker type and
+//! adding `mirror_plugin::<Marker>`. The plugin keeps the row-to-entity
+//! mapping, spawns, updates and despawns from the table's row events, and
+//! rebuilds everything from scratch when a connection comes up. Rows that
+//! merely leave the subscribed queries are despawned the same way.
+
+use bevy::ecs::system::{StaticSystemParam, SystemParam, SystemParamItem};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use super::connection::{ConnectedEvent, DeleteEvent, InsertEvent, OutOfScopeEvent, UpdateEvent};
+
+/// How one table's rows become Bevy entities
+pub trait TableMirror: Send + Sync + 'static {
+  type Row: Send + Sync + 'static;
+  /// Whatever `spawn` and `update` need besides `Commands`
+  type Params: SystemParam + 'static;
+
+  /// Table name, for logs
+  const TABLE: &'static str;
+
+  /// Primary key of a row
+  fn key(row: &Self::Row) -> u64;
+
+  /// How a row shows up in logs
+  fn label(row: &Self::Row) -> String {
+    format!("{} {}", Self::TABLE, Self::key(row))
+  }
+
+  fn spawn(row: &Self::Row, commands: &mut Commands, params: &mut SystemParamItem<Self::Params>) -> Entity;
+
+  fn update(row: &Self::Row, entity: Entity, commands: &mut Commands, params: &mut SystemParamItem<Self::Params>);
+}
+
+/// Mapping between a table's primary keys and the Bevy entities mirroring them
+#[derive(Resource)]
+pub struct Mapping<T: Send + Sync + 'static> {
+  pub spacetime_to_bevy: HashMap<u64, Entity>,
+  pub bevy_to_spacetime: HashMap<Entity, u64>,
+  row: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> Default for Mapping<T> {
+  fn default() -> Self {
+    Self {
+      spacetime_to_bevy: HashMap::new(),
+      bevy_to_spacetime: HashMap::new(),
+      row: PhantomData,
+    }
+  }
+}
+
+impl<T: Send + Sync + 'static> Mapping<T> {
+  pub fn insert(&mut self, spacetime_id: u64, bevy_entity: Entity) {
+    self.spacetime_to_bevy.insert(spacetime_id, bevy_entity);
+    self.bevy_to_spacetime.insert(bevy_entity, spacetime_id);
+  }
+
+  pub fn remove_by_spacetime_id(&mut self, spacetime_id: u64) -> Option<Entity> {
+    if let Some(bevy_entity) = self.spacetime_to_bevy.remove(&spacetime_id) {
+      self.bevy_to_spacetime.remove(&bevy_entity);
+      Some(bevy_entity)
+    } else {
+      None
+    }
+  }
+
+  pub fn get_bevy_entity(&self, spacetime_id: u64) -> Option<Entity> {
+    self.spacetime_to_bevy.get(&spacetime_id).copied()
+  }
+
+  pub fn get_spacetime_id(&self, bevy_entity: Entity) -> Option<u64> {
+    self.bevy_to_spacetime.get(&bevy_entity).copied()
+  }
+
+  /// Forget every row, handing back the entities that mirrored them
+  fn drain(&mut self) -> Vec<Entity> {
+    self.bevy_to_spacetime.clear();
+    self.spacetime_to_bevy.drain().map(|(_, bevy_entity)| bevy_entity).collect()
+  }
+}
+
+/// Every mirror clears in `Clear`, then applies row events in `Sync`
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MirrorSystems {
+  Clear,
+  Sync,
+}
+
+pub fn mirror_plugin<M: TableMirror>(app: &mut App) {
+  app.init_resource::<Mapping<M::Row>>()
+    .add_systems(
+      Update,
+      (
+        clear_mirror_system::<M>.in_set(MirrorSystems::Clear),
+        sync_mirror_system::<M>.in_set(MirrorSystems::Sync),
+      ),
+    );
+}
+
+/// Despawn every mirrored row when a connection comes up - the new
+/// subscription inserts the current ones again
+fn clear_mirror_system<M: TableMirror>(
+  mut commands: Commands,
+  mut mapping: ResMut<Mapping<M::Row>>,
+  mut events: EventReader<ConnectedEvent>,
+) {
+  if events.read().count() == 0 {
+    return;
+  }
+  let stale = mapping.drain();
+  // Mirrored rows can be children of each other, so some are already gone
+  for bevy_entity in &stale {
+    commands.entity(*bevy_entity).try_despawn();
+  }
+  if !stale.is_empty() {
+    info!("Cleared {} stale {} rows", stale.len(), M::TABLE);
+  }
+}
+
+fn spawn_row<M: TableMirror>(
+  row: &M::Row,
+  commands: &mut Commands,
+  mapping: &mut Mapping<M::Row>,
+  params: &mut SystemParamItem<M::Params>,
+) {
+  let bevy_entity = M::spawn(row, commands, params);
+  mapping.insert(M::key(row), bevy_entity);
+  info!("Spawned {} -> Bevy Entity {:?}", M::label(row), bevy_entity);
+}
+
+fn sync_mirror_system<M: TableMirror>(
+  mut commands: Commands,
+  mut mapping: ResMut<Mapping<M::Row>>,
+  mut insert_events: EventReader<InsertEvent<M::Row>>,
+  mut update_events: EventReader<UpdateEvent<M::Row>>,
+  mut delete_events: EventReader<DeleteEvent<M::Row>>,
+  mut out_of_scope_events: EventReader<OutOfScopeEvent<M::Row>>,
+  mut params: StaticSystemParam<M::Params>,
+) {
+  let params = &mut *params;
+
+  let insert_count = insert_events.len();
+  let delete_count = delete_events.len();
+  if insert_count > 0 || delete_count > 0 {
+    debug!("{} events - Inserts: {}, Updates: {}, Deletes: {}",
+           M::TABLE, insert_count, update_events.len(), delete_count);
+  }
+
+  for event in insert_events.read() {
+    let row = &event.row;
+    match mapping.get_bevy_entity(M::key(row)) {
+      // Already mirrored, e.g. from an update that arrived first
+      Some(bevy_entity) => M::update(row, bevy_entity, &mut commands, params),
+      None => spawn_row::<M>(row, &mut commands, &mut mapping, params),
+    }
+  }
+
+  for event in update_events.read() {
+    let row = &event.new;
+    match mapping.get_bevy_entity(M::key(row)) {
+      Some(bevy_entity) => M::update(row, bevy_entity, &mut commands, params),
+      None => {
+        // The insert was missed - the new row has everything needed to spawn it
+        debug!("Update for unmirrored {}, spawning it", M::label(row));
+        spawn_row::<M>(row, &mut commands, &mut mapping, params);
+      },
+    }
+  }
+
+  for event in delete_events.read() {
+    let row = &event.row;
+    match mapping.remove_by_spacetime_id(M::key(row)) {
+      Some(bevy_entity) => {
+        commands.entity(bevy_entity).try_despawn();
+        info!("Despawned {} (Bevy Entity {:?})", M::label(row), bevy_entity);
+      },
+      None => debug!("Delete for unmirrored {}", M::label(row)),
+    }
+  }
+
+  for event in out_of_scope_events.read() {
+    let row = &event.row;
+    if let Some(bevy_entity) = mapping.remove_by_spacetime_id(M::key(row)) {
+      commands.entity(bevy_entity).try_despawn();
+      debug!("Dropped {} out of scope (Bevy Entity {:?})", M::label(row), bevy_entity);
+    }
+  }
+}
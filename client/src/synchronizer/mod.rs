@@ -1,80 +1,83 @@
 use bevy::prelude::*;
-use bevy_spacetimedb::{
-  register_reducers, tables, StdbConnectedEvent, StdbConnection, StdbConnectionErrorEvent, StdbDisconnectedEvent, StdbPlugin
-};
-// use spacetimedb_sdk::{
-//   Table,
-// };
+use spacetimedb_sdk::DbContext;
 
+pub mod commands;
+mod connection;
+mod connection_settings;
+pub mod diagnostics;
 mod entity;
+mod interest;
+mod mirror;
+mod node;
+mod piloting;
+mod placement;
+mod selection;
+mod types;
 mod waypoint;
 
-use crate::spacetime_bindings::*;
-
-use entity::{sync_entities_system, rotation_interpolation_system, EntityMapping};
-use waypoint::{sync_waypoints_system, WaypointMapping};
+pub use connection::ConnectionStatus;
+use commands::commands_plugin;
+use connection::{connection_plugin, ConnectedEvent, ServerConnection};
+use connection_settings::ConnectionSettings;
+use diagnostics::diagnostics_plugin;
+use entity::{attach_entities_system, observe_update_timing_system, motion_interpolation_system, EntityMirror, ServerUpdateTiming};
+use interest::{area_of_interest_system, AreaOfInterest};
+use mirror::{mirror_plugin, MirrorSystems};
+use node::{floating_origin_system, NodeMirror};
+use piloting::{claim_ship_system, reconcile_pilot_system, pilot_input_system, predicted_transform_system, Piloting};
+use placement::{waypoint_placement_system, WaypointPlacement};
+use selection::{default_selection_system, select_on_click};
+use waypoint::{attach_waypoints_system, WaypointMirror};
 
 pub fn synchronizer_plugin(app: &mut App) {
-  app.add_plugins(
-    StdbPlugin::default()
-      .with_connection(|send_connected, send_disconnected, send_connect_error, _| {{
-        let conn = DbConnection::builder()
-          .with_module_name("test-reducer")
-          .with_uri("http://localhost:3000")
-          .on_connect_error(move |_ctx, err| {
-            send_connect_error.send(StdbConnectionErrorEvent { err }).unwrap();
-          })
-          .on_disconnect(move |_ctx, err| {
-            send_disconnected.send(StdbDisconnectedEvent { err }).unwrap();
-          })
-          .on_connect(move |_ctx, _id, _c| {
-            send_connected.send(StdbConnectedEvent {}).unwrap();
-          })
-          .build()
-          .expect("Failed to create DbConnection");
-        
-        conn.run_threaded();
-        conn
-      }})
-      .with_events(|plugin, app, db, _reducers| {{
-        tables!(
-          entity,
-          node,
-          waypoint,
-        );
-
-        register_reducers!();
-      }}),
-  );
-  app.init_resource::<EntityMapping>()
-  .init_resource::<WaypointMapping>()
+  app.insert_resource(ConnectionSettings::resolve())
+  .add_plugins((connection_plugin, commands_plugin, diagnostics_plugin))
+  .add_plugins((
+    mirror_plugin::<NodeMirror>,
+    mirror_plugin::<EntityMirror>,
+    mirror_plugin::<WaypointMirror>,
+  ))
+  .init_resource::<ServerUpdateTiming>()
+  .init_resource::<Piloting>()
+  .init_resource::<AreaOfInterest>()
+  .init_resource::<WaypointPlacement>()
+  .add_observer(select_on_click)
+  // Rebuild the mirrored world from scratch on every (re)connect
+  .configure_sets(Update, (MirrorSystems::Clear, MirrorSystems::Sync).chain())
   .add_systems(
     Update,
     (
-      on_connected,
-      sync_entities_system,
-      sync_waypoints_system,
-      rotation_interpolation_system,
+      on_connected.in_set(MirrorSystems::Clear),
+      observe_update_timing_system.before(MirrorSystems::Sync),
+      // Entities and waypoints follow the ship, see interest.rs
+      area_of_interest_system.after(MirrorSystems::Clear),
+      (
+        // Entities and their waypoints sit in their node's frame
+        (attach_entities_system, attach_waypoints_system).chain(),
+        floating_origin_system,
+        motion_interpolation_system,
+        // The piloted ship runs ahead of the server and overrides the interpolated transform
+        (claim_ship_system, reconcile_pilot_system, pilot_input_system, predicted_transform_system).chain(),
+        (default_selection_system, waypoint_placement_system).chain(),
+      ).chain().after(MirrorSystems::Sync),
     ),
   );
 }
 
 
 fn on_connected(
-  mut events: EventReader<StdbConnectedEvent>,
-  stdb: Res<StdbConnection<DbConnection>>,
+  mut events: EventReader<ConnectedEvent>,
+  server: Res<ServerConnection>,
 ) {
   for _ in events.read() {
+    let Some(conn) = server.get() else {
+      continue;
+    };
     info!("Connected to SpacetimeDB!");
 
-    stdb.subscribe()
-      .on_applied(|_| info!("entity updated"))
-      .on_error(|_, err| error!("Error in entity subscription: {}", err))
-      .subscribe("SELECT * FROM entity");
-      
-    stdb.subscribe()
-      .on_applied(|_| info!("waypoint updated"))
-      .on_error(|_, err| error!("Error in waypoint subscription: {}", err))
-      .subscribe("SELECT * FROM waypoint");
+    conn.subscription_builder()
+      .on_applied(|_| info!("node updated"))
+      .on_error(|_, err| error!("Error in node subscription: {}", err))
+      .subscribe("SELECT * FROM node");
   }
 }
@@ -0,0 +1,195 @@
+use bevy::ecs::system::SystemParamItem;
+use bevy::math::DVec3 as BevyDVec3;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use super::entity::EntityNode;
+use super::mirror::{Mapping, TableMirror};
+use crate::components::{PlayerControlled, Ship};
+
+use crate::spacetime_bindings::{
+  Node as DbNode, DVec3, DQuat,
+};
+use shared::NodeName;
+
+/// Component to mark celestial body entities
+#[derive(Component)]
+#[allow(dead_code)]
+pub struct NodeMarker {
+  pub spacetime_id: u64,
+  pub name: NodeName,
+  pub parent_id: Option<u64>,
+  /// Position in the parent's frame, at full precision
+  pub position: BevyDVec3,
+  pub radius: f64,
+}
+
+/// Mapping between SpacetimeDB node IDs and Bevy entity IDs
+pub type NodeMapping = Mapping<DbNode>;
+
+/// Convert SpacetimeDB DVec3 to Bevy Vec3
+fn dvec3_to_vec3(dvec3: &DVec3) -> Vec3 {
+  Vec3::new(dvec3.x as f32, dvec3.y as f32, dvec3.z as f32)
+}
+
+fn dvec3_to_dvec3(dvec3: &DVec3) -> BevyDVec3 {
+  BevyDVec3::new(dvec3.x, dvec3.y, dvec3.z)
+}
+
+/// Convert SpacetimeDB DQuat to Bevy Quat
+fn dquat_to_quat(dquat: &DQuat) -> Quat {
+  Quat::from_xyzw(dquat.x as f32, dquat.y as f32, dquat.z as f32, dquat.w as f32)
+}
+
+/// Surface colour for a body, roughly what it looks like from space
+fn node_color(name: &NodeName) -> &'static str {
+  match name {
+    NodeName::Sun => "#fff2a8",
+    NodeName::Mercury | NodeName::Moon | NodeName::Ceres => "#a8a39d",
+    NodeName::Venus => "#e8d3a0",
+    NodeName::Earth => "#4a90e2",
+    NodeName::Mars => "#c1440e",
+    NodeName::Jupiter | NodeName::Saturn => "#d8b98a",
+    NodeName::Uranus | NodeName::Neptune => "#7fc8d8",
+    NodeName::Io => "#e6d35a",
+    NodeName::Europa | NodeName::Enceladus => "#efece4",
+    NodeName::Titan => "#d9a441",
+    _ => "#8c8c8c",
+  }
+}
+
+/// Local transform of a node within its parent's frame
+fn node_transform(db_node: &DbNode) -> Transform {
+  Transform::from_translation(dvec3_to_vec3(&db_node.node_position))
+    .with_rotation(dquat_to_quat(&db_node.node_rotation))
+}
+
+/// Spawn a new celestial body based on database data
+fn spawn_node(
+  commands: &mut Commands,
+  meshes: &mut ResMut<Assets<Mesh>>,
+  materials: &mut ResMut<Assets<StandardMaterial>>,
+  db_node: &DbNode,
+) -> Entity {
+  let name = NodeName::from(db_node.name.clone());
+  let label = Name::new(format!("Node: {}", name.as_str()));
+  let marker = NodeMarker {
+    spacetime_id: db_node.id,
+    name,
+    parent_id: db_node.parent_id,
+    position: dvec3_to_dvec3(&db_node.node_position),
+    radius: db_node.radius,
+  };
+
+  // Custom nodes may be bare reference frames without a body
+  if db_node.radius <= 0.0 {
+    return commands.spawn((node_transform(db_node), Visibility::default(), marker, label)).id();
+  }
+
+  // Root nodes are stars and light themselves
+  let emissive = if db_node.parent_id.is_none() {
+    LinearRgba::rgb(1.0, 0.9, 0.5)
+  } else {
+    LinearRgba::BLACK
+  };
+
+  commands.spawn((
+    Mesh3d(meshes.add(Sphere::new(db_node.radius as f32))),
+    MeshMaterial3d(materials.add(StandardMaterial {
+      base_color: Srgba::hex(node_color(&marker.name)).unwrap().into(),
+      emissive,
+      metallic: 0.0,
+      perceptual_roughness: 0.9,
+      ..default()
+    })),
+    node_transform(db_node),
+    marker,
+    label,
+  )).id()
+}
+
+/// Mirrors the `node` table
+pub struct NodeMirror;
+
+impl TableMirror for NodeMirror {
+  type Row = DbNode;
+  type Params = (
+    ResMut<'static, Assets<Mesh>>,
+    ResMut<'static, Assets<StandardMaterial>>,
+    Query<'static, 'static, (&'static mut Transform, &'static mut NodeMarker)>,
+  );
+
+  const TABLE: &'static str = "node";
+
+  fn key(row: &DbNode) -> u64 {
+    row.id
+  }
+
+  fn label(row: &DbNode) -> String {
+    format!("node {:?} (ID: {})", row.name, row.id)
+  }
+
+  fn spawn(row: &DbNode, commands: &mut Commands, (meshes, materials, _): &mut SystemParamItem<Self::Params>) -> Entity {
+    spawn_node(commands, meshes, materials, row)
+  }
+
+  fn update(row: &DbNode, bevy_entity: Entity, _: &mut Commands, (_, _, node_query): &mut SystemParamItem<Self::Params>) {
+    if let Ok((mut transform, mut marker)) = node_query.get_mut(bevy_entity) {
+      *transform = node_transform(row);
+      marker.position = dvec3_to_dvec3(&row.node_position);
+      marker.radius = row.radius;
+      // Moved to another parent by an admin - placed anew by floating_origin_system
+      marker.parent_id = row.parent_id;
+    } else {
+      debug!("Transform update failed for node {} - likely timing issue", row.id);
+    }
+  }
+}
+
+/// The Bevy entity mirroring the named node
+pub fn node_entity(nodes: &Query<(Entity, &NodeMarker)>, name: &NodeName) -> Option<Entity> {
+  nodes.iter().find(|(_, marker)| &marker.name == name).map(|(bevy_entity, _)| bevy_entity)
+}
+
+/// Positions in the solar system are far too large for f32 transforms, so
+/// every node is placed relative to the followed ship's node. The offsets are
+/// worked out in f64 and only the result is narrowed to f32, which keeps
+/// everything near the ship precise. Nodes aren't parented to each other for
+/// the same reason - Bevy would add the large offsets up again in f32.
+pub fn floating_origin_system(
+  ships: Query<(&EntityNode, Has<PlayerControlled>), With<Ship>>,
+  mut nodes: Query<(&NodeMarker, &mut Transform)>,
+) {
+  // The piloted ship if there is one, any ship otherwise
+  let focus = ships.iter()
+    .max_by_key(|(_, piloted)| *piloted)
+    .map(|(node, _)| &node.0);
+
+  let frames: HashMap<u64, (Option<u64>, BevyDVec3)> = nodes.iter()
+    .map(|(marker, _)| (marker.spacetime_id, (marker.parent_id, marker.position)))
+    .collect();
+  // Position relative to the root of the node's chain
+  let absolute = |mut id: u64| {
+    let mut position = BevyDVec3::ZERO;
+    // Bounded in case a half-applied move briefly leaves a cycle
+    for _ in 0..frames.len() {
+      let Some((parent_id, local)) = frames.get(&id) else {
+        break;
+      };
+      position += *local;
+      match parent_id {
+        Some(parent_id) => id = *parent_id,
+        None => break,
+      }
+    }
+    position
+  };
+
+  let origin = focus
+    .and_then(|name| nodes.iter().find(|(marker, _)| &marker.name == name))
+    .map(|(marker, _)| absolute(marker.spacetime_id))
+    .unwrap_or(BevyDVec3::ZERO);
+
+  for (marker, mut transform) in nodes.iter_mut() {
+    transform.translation = (absolute(marker.spacetime_id) - origin).as_vec3();
+  }
+}
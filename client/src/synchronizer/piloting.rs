@@ -0,0 +1,231 @@
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use spacetimedb_sdk::DbContext;
+
+use shared::math::DVec3;
+use shared::piloting::{self, InputBuffer, ShipParameters};
+use shared::trajectory::{FlightState, DELTA_TIME};
+
+use super::commands::TakeControl;
+use super::connection::{ConnectedEvent, DeleteEvent, InsertEvent, ServerConnection, UpdateEvent};
+use super::connection_settings::ConnectionSettings;
+use super::entity::EntityMapping;
+use crate::components::{MotionInterpolation, PlayerControlled};
+use crate::spacetime_bindings::{pilot_input, EntityTableAccess, Pilot};
+
+/// Catch up at most this many solver steps per frame after a hitch
+const MAX_STEPS_PER_FRAME: u32 = 5;
+/// Corrections larger than this (metres) are applied at once instead of blended
+const SNAP_DISTANCE: f64 = 50.0;
+/// How quickly a blended correction fades out, per second
+const CORRECTION_RATE: f64 = 10.0;
+
+/// The ship this client flies, run ahead of the server
+pub struct PredictedShip {
+  pub entity_id: u64,
+  pub state: FlightState,
+  pub parameters: ShipParameters,
+  pub buffer: InputBuffer,
+  /// Where the ship was drawn minus where the latest reconciliation put it,
+  /// faded out so corrections don't jump
+  pub correction: DVec3,
+  accumulator: f64,
+}
+
+#[derive(Resource, Default)]
+pub struct Piloting {
+  /// `take_control` was sent on the current connection
+  requested: bool,
+  pub ship: Option<PredictedShip>,
+}
+
+/// Ask to fly the configured ship once it is in the mirrored world
+pub fn claim_ship_system(
+  settings: Res<ConnectionSettings>,
+  server: Res<ServerConnection>,
+  mut piloting: ResMut<Piloting>,
+  mut connected: EventReader<ConnectedEvent>,
+  mut take_control: EventWriter<TakeControl>,
+) {
+  if connected.read().count() > 0 {
+    *piloting = Piloting::default();
+  }
+  let (Some(designation), Some(conn)) = (&settings.ship, server.get()) else {
+    return;
+  };
+  if piloting.requested {
+    return;
+  }
+  let Some(ship) = conn.db.entity().iter().find(|entity| &entity.designation == designation) else {
+    return;
+  };
+
+  info!("Taking control of {} (ID: {})", designation, ship.id);
+  take_control.write(TakeControl { entity_id: ship.id });
+  piloting.requested = true;
+}
+
+/// The state the server reached after the pilot row's acknowledged input
+fn server_state(pilot: &Pilot) -> FlightState {
+  FlightState::from_pose(
+    pilot.position.clone().into(),
+    pilot.velocity.clone().into(),
+    pilot.rotation.clone().into(),
+    pilot.rotational_velocity.clone().into(),
+  )
+}
+
+/// Start or stop predicting, and rewind and replay on every server state
+pub fn reconcile_pilot_system(
+  server: Res<ServerConnection>,
+  mut piloting: ResMut<Piloting>,
+  mut insert_events: EventReader<InsertEvent<Pilot>>,
+  mut update_events: EventReader<UpdateEvent<Pilot>>,
+  mut delete_events: EventReader<DeleteEvent<Pilot>>,
+) {
+  let Some(conn) = server.get() else {
+    return;
+  };
+  let identity = conn.try_identity();
+  let ours = |pilot: &Pilot| Some(pilot.identity) == identity;
+
+  for event in delete_events.read().filter(|event| ours(&event.row)) {
+    if piloting.ship.as_ref().is_some_and(|ship| ship.entity_id == event.row.entity_id) {
+      info!("Released control of entity {}", event.row.entity_id);
+      piloting.ship = None;
+    }
+  }
+
+  for event in insert_events.read().filter(|event| ours(&event.row)) {
+    let pilot = &event.row;
+    let Some(entity) = conn.db.entity().id().find(&pilot.entity_id) else {
+      warn!("Piloting unknown entity {}", pilot.entity_id);
+      continue;
+    };
+    info!("Flying {} from input {}", entity.designation, pilot.acknowledged + 1);
+    piloting.ship = Some(PredictedShip {
+      entity_id: pilot.entity_id,
+      state: server_state(pilot),
+      parameters: ShipParameters {
+        integrator: pilot.integrator.into(),
+        gravitational_parameter: pilot.gravitational_parameter,
+        mass: entity.mass,
+        max_thrust: entity.max_impulse.into(),
+      },
+      buffer: InputBuffer::resume(pilot.acknowledged),
+      correction: DVec3::ZERO,
+      accumulator: 0.0,
+    });
+  }
+
+  for event in update_events.read().filter(|event| ours(&event.new)) {
+    let pilot = &event.new;
+    let Some(ship) = piloting.ship.as_mut().filter(|ship| ship.entity_id == pilot.entity_id) else {
+      continue;
+    };
+    ship.parameters.integrator = pilot.integrator.into();
+    ship.parameters.gravitational_parameter = pilot.gravitational_parameter;
+
+    let drawn = ship.state.position + ship.correction;
+    ship.state = ship.buffer.reconcile(pilot.acknowledged, server_state(pilot), &ship.parameters);
+    let error = drawn - ship.state.position;
+    ship.correction = if error.length() > SNAP_DISTANCE {
+      warn!("Prediction was {:.1}m off, snapping", error.length());
+      DVec3::ZERO
+    } else {
+      error
+    };
+  }
+}
+
+/// Body-frame thrust from the keyboard: W/S forward and back, Space/Ctrl up
+/// and down, D/A right and left
+fn keyboard_thrust(keys: &ButtonInput<KeyCode>, max_thrust: DVec3) -> DVec3 {
+  let pressed = |codes: &[KeyCode]| if keys.any_pressed(codes.iter().copied()) { 1.0 } else { 0.0 };
+  let axis = |positive: &[KeyCode], negative: &[KeyCode]| pressed(positive) - pressed(negative);
+  let forward = axis(&[KeyCode::KeyW], &[KeyCode::KeyS]);
+  let up = axis(&[KeyCode::Space], &[KeyCode::ControlLeft, KeyCode::ControlRight]);
+  let right = axis(&[KeyCode::KeyD], &[KeyCode::KeyA]);
+
+  DVec3::new(
+    if forward >= 0.0 { forward * max_thrust.x } else { forward * max_thrust.y },
+    up * max_thrust.z,
+    right * max_thrust.z,
+  )
+}
+
+/// Sample the controls once per solver step, send them and fly them locally
+pub fn pilot_input_system(
+  time: Res<Time>,
+  keys: Res<ButtonInput<KeyCode>>,
+  focus: Res<InputFocus>,
+  server: Res<ServerConnection>,
+  mut piloting: ResMut<Piloting>,
+) {
+  let (Some(conn), Some(ship)) = (server.get(), piloting.ship.as_mut()) else {
+    return;
+  };
+
+  ship.accumulator += time.delta_secs_f64();
+  let mut steps = 0;
+  while ship.accumulator >= DELTA_TIME {
+    ship.accumulator -= DELTA_TIME;
+    steps += 1;
+    if steps > MAX_STEPS_PER_FRAME {
+      // Too far behind to catch up - drop the time rather than flood the server
+      ship.accumulator = 0.0;
+      break;
+    }
+
+    // Typing into a text field doesn't fly the ship
+    let thrust = if focus.get().is_some() {
+      DVec3::ZERO
+    } else {
+      keyboard_thrust(&keys, ship.parameters.max_thrust)
+    };
+    let input = ship.buffer.record(thrust);
+    // Not a command - twenty a second would drown the command log, and the
+    // acks come back on the pilot row
+    if let Err(err) = conn.reducers.pilot_input(ship.entity_id, input.sequence, input.thrust.into()) {
+      error!("Failed to send pilot input {}: {}", input.sequence, err);
+    }
+    piloting::step(&mut ship.state, input.thrust, &ship.parameters);
+  }
+
+  // Fade out whatever the last reconciliation moved
+  ship.correction *= (-CORRECTION_RATE * time.delta_secs_f64()).exp();
+}
+
+/// Draw the piloted ship where the prediction has it, instead of interpolating
+/// between server updates
+pub fn predicted_transform_system(
+  mut commands: Commands,
+  piloting: Res<Piloting>,
+  entity_mapping: Res<EntityMapping>,
+  mut transforms: Query<(Entity, &mut Transform, Has<PlayerControlled>)>,
+  controlled: Query<Entity, With<PlayerControlled>>,
+) {
+  let piloted = piloting.ship.as_ref()
+    .and_then(|ship| entity_mapping.get_bevy_entity(ship.entity_id).map(|entity| (ship, entity)));
+
+  for entity in controlled.iter() {
+    if piloted.is_none_or(|(_, piloted)| piloted != entity) {
+      commands.entity(entity).remove::<PlayerControlled>();
+    }
+  }
+
+  let Some((ship, entity)) = piloted else {
+    return;
+  };
+  let Ok((entity, mut transform, controlled)) = transforms.get_mut(entity) else {
+    return;
+  };
+  if !controlled {
+    commands.entity(entity).insert(PlayerControlled).remove::<MotionInterpolation>();
+  }
+
+  let position = ship.state.position + ship.correction;
+  let rotation = ship.state.orientation();
+  transform.translation = Vec3::new(position.x as f32, position.y as f32, position.z as f32);
+  transform.rotation = Quat::from_xyzw(rotation.x as f32, rotation.y as f32, rotation.z as f32, rotation.w as f32);
+}
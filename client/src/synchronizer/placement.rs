@@ -0,0 +1,207 @@
+//! Placing waypoints for the selected ship by clicking in the 3D view.
+//!
+//! With the tool on (P), a ghost marker follows the cursor across the plane
+//! through the ship that is level in its node's frame. Holding Shift and
+//! moving the mouse raises or lowers it instead. A click pins the ghost,
+//! Enter queues it as a waypoint and Escape unpins it or leaves the tool. The
+//! waypoint itself arrives back through the waypoint mirror like any other.
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use shared::math::DVec3;
+
+use super::commands::AddWaypoint;
+use super::entity::EntityMapping;
+use super::selection::MAX_CLICK_DURATION;
+use crate::components::{Selected, Ship};
+
+const TOGGLE_KEY: KeyCode = KeyCode::KeyP;
+const HEIGHT_KEYS: [KeyCode; 2] = [KeyCode::ShiftLeft, KeyCode::ShiftRight];
+/// Metres the ghost rises per pixel of mouse movement with Shift held
+const HEIGHT_PER_PIXEL: f32 = 0.1;
+
+/// State of the placement tool
+#[derive(Resource, Default)]
+pub struct WaypointPlacement {
+  pub active: bool,
+  /// The ship the ghost is placed for
+  entity_id: Option<u64>,
+  /// Point on the reference plane, in the ship's node frame
+  anchor: Option<Vec3>,
+  /// Offset from the plane along the node frame's up axis
+  height: f32,
+  /// A click fixed the anchor, the cursor no longer moves it
+  pinned: bool,
+  /// When the left button went down, to tell clicks from drags
+  pressed_at: Option<f64>,
+}
+
+/// Marks the marker showing where the waypoint would go
+#[derive(Component)]
+pub struct WaypointGhost;
+
+impl WaypointPlacement {
+  fn target(&self) -> Option<Vec3> {
+    self.anchor.map(|anchor| anchor + Vec3::Y * self.height)
+  }
+
+  fn reset(&mut self) {
+    self.anchor = None;
+    self.height = 0.0;
+    self.pinned = false;
+  }
+}
+
+/// Where the cursor ray crosses the horizontal plane at `height`, in a frame
+/// whose inverse is `to_frame`
+fn cursor_on_plane(ray: Ray3d, to_frame: &Affine3A, height: f32) -> Option<Vec3> {
+  let origin = to_frame.transform_point3(ray.origin);
+  let direction = Dir3::new(to_frame.transform_vector3(*ray.direction)).ok()?;
+  let distance = Ray3d::new(origin, direction)
+    .intersect_plane(Vec3::Y * height, InfinitePlane3d::new(Vec3::Y))?;
+  Some(origin + *direction * distance)
+}
+
+/// Keyboard, mouse and cursor as the tool sees them
+#[derive(SystemParam)]
+pub struct PlacementInput<'w, 's> {
+  keys: Res<'w, ButtonInput<KeyCode>>,
+  mouse_buttons: Res<'w, ButtonInput<MouseButton>>,
+  mouse_motion: Res<'w, AccumulatedMouseMotion>,
+  focus: Res<'w, InputFocus>,
+  window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+  camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<Camera3d>>,
+}
+
+impl PlacementInput<'_, '_> {
+  /// Key presses, unless they go to a text field
+  fn just_pressed(&self, key: KeyCode) -> bool {
+    self.focus.get().is_none() && self.keys.just_pressed(key)
+  }
+
+  /// The ray from the camera through the cursor
+  fn cursor_ray(&self) -> Option<Ray3d> {
+    let cursor = self.window.single().ok()?.cursor_position()?;
+    let (camera, camera_transform) = self.camera.single().ok()?;
+    camera.viewport_to_world(camera_transform, cursor).ok()
+  }
+}
+
+fn spawn_ghost(
+  commands: &mut Commands,
+  meshes: &mut Assets<Mesh>,
+  materials: &mut Assets<StandardMaterial>,
+  frame: Entity,
+) -> Entity {
+  // Same shape as a placed waypoint, see `spawn_waypoint`
+  commands.spawn((
+    Mesh3d(meshes.add(Cuboid::new(0.2, 1.5, 0.2))),
+    MeshMaterial3d(materials.add(StandardMaterial {
+      base_color: Srgba::new(1.0, 0.3, 0.3, 0.4).into(),
+      alpha_mode: AlphaMode::Blend,
+      unlit: true,
+      ..default()
+    })),
+    Transform::default(),
+    Pickable::IGNORE,
+    ChildOf(frame),
+    WaypointGhost,
+    Name::new("Waypoint ghost"),
+  )).id()
+}
+
+pub fn waypoint_placement_system(
+  mut commands: Commands,
+  mut meshes: ResMut<Assets<Mesh>>,
+  mut materials: ResMut<Assets<StandardMaterial>>,
+  mut placement: ResMut<WaypointPlacement>,
+  mut add_waypoint: EventWriter<AddWaypoint>,
+  mut gizmos: Gizmos,
+  input: PlacementInput,
+  time: Res<Time>,
+  entity_mapping: Res<EntityMapping>,
+  selected: Query<(Entity, &Transform, &ChildOf), (With<Selected>, With<Ship>)>,
+  frames: Query<&GlobalTransform>,
+  ghosts: Query<Entity, With<WaypointGhost>>,
+) {
+  if input.just_pressed(TOGGLE_KEY) {
+    placement.active = !placement.active;
+    placement.reset();
+    if placement.active {
+      info!("Placing waypoints: click to pick a point, Shift to change height, Enter to confirm, Escape to cancel");
+    }
+  }
+  if placement.active && input.just_pressed(KeyCode::Escape) {
+    if placement.pinned {
+      placement.reset();
+    } else {
+      placement.active = false;
+    }
+  }
+
+  let ship = selected.single().ok().and_then(|(ship, transform, child_of)| {
+    let entity_id = entity_mapping.get_spacetime_id(ship)?;
+    let frame = frames.get(child_of.parent()).ok()?;
+    Some((entity_id, transform.translation, child_of.parent(), *frame))
+  });
+  let Some((entity_id, ship_position, frame_entity, frame)) = ship.filter(|_| placement.active) else {
+    for ghost in ghosts.iter() {
+      commands.entity(ghost).despawn();
+    }
+    return;
+  };
+  if placement.entity_id != Some(entity_id) {
+    placement.entity_id = Some(entity_id);
+    placement.reset();
+  }
+  let to_frame = frame.affine().inverse();
+
+  // Raise or lower the ghost, otherwise move it with the cursor
+  if input.keys.any_pressed(HEIGHT_KEYS) {
+    placement.height -= input.mouse_motion.delta.y * HEIGHT_PER_PIXEL;
+  } else if !placement.pinned {
+    if let Some(ray) = input.cursor_ray() {
+      placement.anchor = cursor_on_plane(ray, &to_frame, ship_position.y);
+    }
+  }
+
+  let now = time.elapsed_secs_f64();
+  if input.mouse_buttons.just_pressed(MouseButton::Left) {
+    placement.pressed_at = Some(now);
+  }
+  if input.mouse_buttons.just_released(MouseButton::Left)
+    && placement.pressed_at.take().is_some_and(|pressed_at| now - pressed_at <= MAX_CLICK_DURATION)
+    && placement.anchor.is_some()
+  {
+    placement.pinned = true;
+  }
+
+  let Some(target) = placement.target() else {
+    for ghost in ghosts.iter() {
+      commands.entity(ghost).despawn();
+    }
+    return;
+  };
+
+  if placement.pinned && input.just_pressed(KeyCode::Enter) {
+    add_waypoint.write(AddWaypoint {
+      entity_id,
+      target_position: DVec3::new(target.x as f64, target.y as f64, target.z as f64),
+    });
+    placement.reset();
+  }
+
+  // The ghost goes with its node when that is despawned, e.g. on reconnect
+  let ghost = ghosts.single().unwrap_or_else(|_| spawn_ghost(&mut commands, &mut meshes, &mut materials, frame_entity));
+  commands.entity(ghost).insert((Transform::from_translation(target), ChildOf(frame_entity)));
+
+  // Where it sits relative to the plane and the ship
+  let anchor = placement.anchor.unwrap_or(target);
+  let color = if placement.pinned { Color::srgb(1.0, 0.8, 0.3) } else { Color::srgba(1.0, 0.3, 0.3, 0.6) };
+  gizmos.line(frame.transform_point(anchor), frame.transform_point(target), color);
+  gizmos.line(frame.transform_point(ship_position), frame.transform_point(anchor), color);
+}
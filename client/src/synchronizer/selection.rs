@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use super::entity::EntityMapping;
+use super::placement::WaypointPlacement;
+use crate::components::{PlayerControlled, Selected};
+
+/// Presses held longer than this are camera drags, not clicks
+pub(super) const MAX_CLICK_DURATION: f64 = 0.3;
+
+/// Select a mirrored entity by clicking any part of it. Clicks bubble up from
+/// the mesh that was hit, so the first mirrored ancestor takes it.
+pub fn select_on_click(
+  mut trigger: Trigger<Pointer<Click>>,
+  mut commands: Commands,
+  entity_mapping: Res<EntityMapping>,
+  placement: Res<WaypointPlacement>,
+  selected: Query<Entity, With<Selected>>,
+) {
+  // Clicks place waypoints for the selected ship instead
+  if placement.active
+    || trigger.event().button != PointerButton::Primary
+    || trigger.event().duration.as_secs_f64() > MAX_CLICK_DURATION
+  {
+    return;
+  }
+  let target = trigger.target();
+  let Some(entity_id) = entity_mapping.get_spacetime_id(target) else {
+    return;
+  };
+  trigger.propagate(false);
+
+  for previous in selected.iter() {
+    commands.entity(previous).remove::<Selected>();
+  }
+  commands.entity(target).insert(Selected);
+  info!("Selected entity {}", entity_id);
+}
+
+/// Until something is picked, the piloted ship counts as selected
+pub fn default_selection_system(
+  mut commands: Commands,
+  selected: Query<(), With<Selected>>,
+  piloted: Query<Entity, With<PlayerControlled>>,
+) {
+  if !selected.is_empty() {
+    return;
+  }
+  if let Ok(ship) = piloted.single() {
+    commands.entity(ship).insert(Selected);
+  }
+}
@@ -0,0 +1,159 @@
+//! Conversions between the generated binding types and the `shared` ones.
+//!
+//! `shared` owns `EntityType`, `NodeName`, `Integrator` and the math types;
+//! the bindings carry a generated copy of each. The matches here are exhaustive in both directions, so a
+//! variant added to `shared` without regenerating the bindings fails to build.
+
+use crate::spacetime_bindings as db;
+
+impl From<db::EntityType> for shared::EntityType {
+  fn from(value: db::EntityType) -> Self {
+    match value {
+      db::EntityType::Star => shared::EntityType::Star,
+      db::EntityType::Planet => shared::EntityType::Planet,
+      db::EntityType::Moon => shared::EntityType::Moon,
+      db::EntityType::Asteroid => shared::EntityType::Asteroid,
+      db::EntityType::Comet => shared::EntityType::Comet,
+      db::EntityType::Ship => shared::EntityType::Ship,
+      db::EntityType::Custom(name) => shared::EntityType::Custom(name),
+    }
+  }
+}
+
+impl From<shared::EntityType> for db::EntityType {
+  fn from(value: shared::EntityType) -> Self {
+    match value {
+      shared::EntityType::Star => db::EntityType::Star,
+      shared::EntityType::Planet => db::EntityType::Planet,
+      shared::EntityType::Moon => db::EntityType::Moon,
+      shared::EntityType::Asteroid => db::EntityType::Asteroid,
+      shared::EntityType::Comet => db::EntityType::Comet,
+      shared::EntityType::Ship => db::EntityType::Ship,
+      shared::EntityType::Custom(name) => db::EntityType::Custom(name),
+    }
+  }
+}
+
+impl From<db::NodeName> for shared::NodeName {
+  fn from(value: db::NodeName) -> Self {
+    match value {
+      db::NodeName::Sun => shared::NodeName::Sun,
+      db::NodeName::Mercury => shared::NodeName::Mercury,
+      db::NodeName::Earth => shared::NodeName::Earth,
+      db::NodeName::Mars => shared::NodeName::Mars,
+      db::NodeName::Venus => shared::NodeName::Venus,
+      db::NodeName::Jupiter => shared::NodeName::Jupiter,
+      db::NodeName::Saturn => shared::NodeName::Saturn,
+      db::NodeName::Uranus => shared::NodeName::Uranus,
+      db::NodeName::Neptune => shared::NodeName::Neptune,
+      db::NodeName::Pluto => shared::NodeName::Pluto,
+      db::NodeName::Moon => shared::NodeName::Moon,
+      db::NodeName::Ceres => shared::NodeName::Ceres,
+      db::NodeName::Eris => shared::NodeName::Eris,
+      db::NodeName::Haumea => shared::NodeName::Haumea,
+      db::NodeName::Makemake => shared::NodeName::Makemake,
+      db::NodeName::Phoebe => shared::NodeName::Phoebe,
+      db::NodeName::Titan => shared::NodeName::Titan,
+      db::NodeName::Callisto => shared::NodeName::Callisto,
+      db::NodeName::Ganymede => shared::NodeName::Ganymede,
+      db::NodeName::Io => shared::NodeName::Io,
+      db::NodeName::Europa => shared::NodeName::Europa,
+      db::NodeName::Enceladus => shared::NodeName::Enceladus,
+      db::NodeName::Triton => shared::NodeName::Triton,
+      db::NodeName::Charon => shared::NodeName::Charon,
+      db::NodeName::Titania => shared::NodeName::Titania,
+      db::NodeName::Oberon => shared::NodeName::Oberon,
+      db::NodeName::Rhea => shared::NodeName::Rhea,
+      db::NodeName::Iapetus => shared::NodeName::Iapetus,
+      db::NodeName::Dione => shared::NodeName::Dione,
+      db::NodeName::Tethys => shared::NodeName::Tethys,
+      db::NodeName::Mimas => shared::NodeName::Mimas,
+      db::NodeName::Hyperion => shared::NodeName::Hyperion,
+      db::NodeName::Ariel => shared::NodeName::Ariel,
+      db::NodeName::Umbriel => shared::NodeName::Umbriel,
+      db::NodeName::Miranda => shared::NodeName::Miranda,
+      db::NodeName::Custom(name) => shared::NodeName::Custom(name),
+    }
+  }
+}
+
+impl From<shared::NodeName> for db::NodeName {
+  fn from(value: shared::NodeName) -> Self {
+    match value {
+      shared::NodeName::Sun => db::NodeName::Sun,
+      shared::NodeName::Mercury => db::NodeName::Mercury,
+      shared::NodeName::Earth => db::NodeName::Earth,
+      shared::NodeName::Mars => db::NodeName::Mars,
+      shared::NodeName::Venus => db::NodeName::Venus,
+      shared::NodeName::Jupiter => db::NodeName::Jupiter,
+      shared::NodeName::Saturn => db::NodeName::Saturn,
+      shared::NodeName::Uranus => db::NodeName::Uranus,
+      shared::NodeName::Neptune => db::NodeName::Neptune,
+      shared::NodeName::Pluto => db::NodeName::Pluto,
+      shared::NodeName::Moon => db::NodeName::Moon,
+      shared::NodeName::Ceres => db::NodeName::Ceres,
+      shared::NodeName::Eris => db::NodeName::Eris,
+      shared::NodeName::Haumea => db::NodeName::Haumea,
+      shared::NodeName::Makemake => db::NodeName::Makemake,
+      shared::NodeName::Phoebe => db::NodeName::Phoebe,
+      shared::NodeName::Titan => db::NodeName::Titan,
+      shared::NodeName::Callisto => db::NodeName::Callisto,
+      shared::NodeName::Ganymede => db::NodeName::Ganymede,
+      shared::NodeName::Io => db::NodeName::Io,
+      shared::NodeName::Europa => db::NodeName::Europa,
+      shared::NodeName::Enceladus => db::NodeName::Enceladus,
+      shared::NodeName::Triton => db::NodeName::Triton,
+      shared::NodeName::Charon => db::NodeName::Charon,
+      shared::NodeName::Titania => db::NodeName::Titania,
+      shared::NodeName::Oberon => db::NodeName::Oberon,
+      shared::NodeName::Rhea => db::NodeName::Rhea,
+      shared::NodeName::Iapetus => db::NodeName::Iapetus,
+      shared::NodeName::Dione => db::NodeName::Dione,
+      shared::NodeName::Tethys => db::NodeName::Tethys,
+      shared::NodeName::Mimas => db::NodeName::Mimas,
+      shared::NodeName::Hyperion => db::NodeName::Hyperion,
+      shared::NodeName::Ariel => db::NodeName::Ariel,
+      shared::NodeName::Umbriel => db::NodeName::Umbriel,
+      shared::NodeName::Miranda => db::NodeName::Miranda,
+      shared::NodeName::Custom(name) => db::NodeName::Custom(name),
+    }
+  }
+}
+
+impl From<db::Integrator> for shared::trajectory::Integrator {
+  fn from(value: db::Integrator) -> Self {
+    match value {
+      db::Integrator::SemiImplicitEuler => shared::trajectory::Integrator::SemiImplicitEuler,
+      db::Integrator::VelocityVerlet => shared::trajectory::Integrator::VelocityVerlet,
+      db::Integrator::RungeKutta4 => shared::trajectory::Integrator::RungeKutta4,
+    }
+  }
+}
+
+impl From<shared::trajectory::Integrator> for db::Integrator {
+  fn from(value: shared::trajectory::Integrator) -> Self {
+    match value {
+      shared::trajectory::Integrator::SemiImplicitEuler => db::Integrator::SemiImplicitEuler,
+      shared::trajectory::Integrator::VelocityVerlet => db::Integrator::VelocityVerlet,
+      shared::trajectory::Integrator::RungeKutta4 => db::Integrator::RungeKutta4,
+    }
+  }
+}
+
+impl From<db::DVec3> for shared::math::DVec3 {
+  fn from(value: db::DVec3) -> Self {
+    shared::math::DVec3::new(value.x, value.y, value.z)
+  }
+}
+
+impl From<shared::math::DVec3> for db::DVec3 {
+  fn from(value: shared::math::DVec3) -> Self {
+    db::DVec3 { x: value.x, y: value.y, z: value.z }
+  }
+}
+
+impl From<db::DQuat> for shared::math::DQuat {
+  fn from(value: db::DQuat) -> Self {
+    shared::math::DQuat::from_xyzw(value.x, value.y, value.z, value.w)
+  }
+}
@@ -1,8 +1,7 @@
+use bevy::ecs::system::SystemParamItem;
 use bevy::prelude::*;
-use bevy_spacetimedb::{
-  InsertEvent, UpdateEvent, DeleteEvent,
-};
-use std::collections::HashMap;
+use super::entity::EntityMapping;
+use super::mirror::TableMirror;
 
 use crate::spacetime_bindings::{
   Waypoint as DbWaypoint, DVec3,
@@ -16,37 +15,6 @@ pub struct WaypointMarker {
   pub order_index: u32,
 }
 
-/// Resource to track mapping between SpacetimeDB waypoint IDs and Bevy entity IDs
-#[derive(Resource, Default)]
-pub struct WaypointMapping {
-  pub spacetime_to_bevy: HashMap<u64, Entity>,
-  pub bevy_to_spacetime: HashMap<Entity, u64>,
-}
-
-impl WaypointMapping {
-  pub fn insert(&mut self, spacetime_id: u64, bevy_entity: Entity) {
-    self.spacetime_to_bevy.insert(spacetime_id, bevy_entity);
-    self.bevy_to_spacetime.insert(bevy_entity, spacetime_id);
-  }
-
-  pub fn remove_by_spacetime_id(&mut self, spacetime_id: u64) -> Option<Entity> {
-    if let Some(bevy_entity) = self.spacetime_to_bevy.remove(&spacetime_id) {
-      self.bevy_to_spacetime.remove(&bevy_entity);
-      Some(bevy_entity)
-    } else {
-      None
-    }
-  }
-
-  pub fn get_bevy_entity(&self, spacetime_id: u64) -> Option<Entity> {
-    self.spacetime_to_bevy.get(&spacetime_id).copied()
-  }
-
-  pub fn get_spacetime_id(&self, bevy_entity: Entity) -> Option<u64> {
-    self.bevy_to_spacetime.get(&bevy_entity).copied()
-  }
-}
-
 /// Convert SpacetimeDB DVec3 to Bevy Vec3
 fn dvec3_to_vec3(dvec3: &DVec3) -> Vec3 {
   Vec3::new(dvec3.x as f32, dvec3.y as f32, dvec3.z as f32)
@@ -87,96 +55,62 @@ fn spawn_waypoint(
   )).id()
 }
 
-/// Update waypoint position based on database data
-fn update_waypoint_transform(
-  bevy_entity: Entity,
-  db_waypoint: &DbWaypoint,
-  transform_query: &mut Query<&mut Transform>,
-) -> bool {
-  if let Ok(mut transform) = transform_query.get_mut(bevy_entity) {
-    let new_position = dvec3_to_vec3(&db_waypoint.target_position);
-    transform.translation = new_position;
-    true
-  } else {
-    false
-  }
-}
+/// Mirrors the `waypoint` table
+pub struct WaypointMirror;
 
-/// System to synchronize waypoints from SpacetimeDB
-pub fn sync_waypoints_system(
-  mut commands: Commands,
-  mut meshes: ResMut<Assets<Mesh>>,
-  mut materials: ResMut<Assets<StandardMaterial>>,
-  mut waypoint_mapping: ResMut<WaypointMapping>,
-  mut transform_query: Query<&mut Transform>,
-  mut insert_events: EventReader<InsertEvent<DbWaypoint>>,
-  mut update_events: EventReader<UpdateEvent<DbWaypoint>>,
-  mut delete_events: EventReader<DeleteEvent<DbWaypoint>>,
-) {
-  // Debug logging to see if events are received
-  let insert_count = insert_events.len();
-  let update_count = update_events.len();
-  let delete_count = delete_events.len();
-  
-  if insert_count > 0 || update_count > 0 || delete_count > 0 {
-    info!("Waypoint events received - Inserts: {}, Updates: {}, Deletes: {}", 
-          insert_count, update_count, delete_count);
+impl TableMirror for WaypointMirror {
+  type Row = DbWaypoint;
+  type Params = (
+    ResMut<'static, Assets<Mesh>>,
+    ResMut<'static, Assets<StandardMaterial>>,
+    Query<'static, 'static, &'static mut Transform>,
+  );
+
+  const TABLE: &'static str = "waypoint";
+
+  fn key(row: &DbWaypoint) -> u64 {
+    row.id
   }
 
-  // Handle waypoint insertions
-  for event in insert_events.read() {
-    let db_waypoint = &event.row;
-    info!("Processing waypoint insert: ID {} for entity {} at order {}", 
-          db_waypoint.id, db_waypoint.entity_id, db_waypoint.order_index);
-    
-    // Check if we already have this waypoint (shouldn't happen, but be safe)
-    if let Some(existing_entity) = waypoint_mapping.get_bevy_entity(db_waypoint.id) {
-      warn!("Waypoint {} already exists as Bevy Entity {:?}, skipping insert", 
-            db_waypoint.id, existing_entity);
-      continue;
-    }
+  fn label(row: &DbWaypoint) -> String {
+    format!("waypoint {} (entity {}, order {})", row.id, row.entity_id, row.order_index)
+  }
 
-    // Spawn the new waypoint
-    let bevy_entity = spawn_waypoint(&mut commands, &mut meshes, &mut materials, db_waypoint);
-    
-    // Track the mapping
-    waypoint_mapping.insert(db_waypoint.id, bevy_entity);
-    
-    info!("Successfully inserted waypoint: {} -> Bevy Entity {:?}", 
-          db_waypoint.id, bevy_entity);
+  fn spawn(row: &DbWaypoint, commands: &mut Commands, (meshes, materials, _): &mut SystemParamItem<Self::Params>) -> Entity {
+    spawn_waypoint(commands, meshes, materials, row)
   }
 
-  // Handle waypoint updates
-  for event in update_events.read() {
-    let db_waypoint = &event.new;
-    debug!("Processing waypoint update: ID {} for entity {}", 
-           db_waypoint.id, db_waypoint.entity_id);
-    
-    if let Some(bevy_entity) = waypoint_mapping.get_bevy_entity(db_waypoint.id) {
-      // Try to update the transform
-      if !update_waypoint_transform(bevy_entity, db_waypoint, &mut transform_query) {
-        debug!("Transform update failed for waypoint {} but keeping mapping - likely timing issue", 
-               db_waypoint.id);
-      }
+  fn update(row: &DbWaypoint, bevy_entity: Entity, _: &mut Commands, (_, _, transform_query): &mut SystemParamItem<Self::Params>) {
+    if let Ok(mut transform) = transform_query.get_mut(bevy_entity) {
+      transform.translation = dvec3_to_vec3(&row.target_position);
     } else {
-      warn!("Received update for unknown waypoint {} - no mapping found. Skipping update.", 
-            db_waypoint.id);
+      debug!("Transform update failed for waypoint {} - likely timing issue", row.id);
     }
   }
+}
 
-  // Handle waypoint deletions
-  for event in delete_events.read() {
-    let db_waypoint = &event.row;
-    info!("Processing waypoint delete: ID {} for entity {}", 
-          db_waypoint.id, db_waypoint.entity_id);
-    
-    if let Some(bevy_entity) = waypoint_mapping.remove_by_spacetime_id(db_waypoint.id) {
-      // Despawn the Bevy entity
-      commands.entity(bevy_entity).despawn();
-      info!("Successfully deleted waypoint: {} (Bevy Entity {:?})", 
-            db_waypoint.id, bevy_entity);
-    } else {
-      warn!("Received delete for unknown waypoint {}", db_waypoint.id);
+/// Waypoint targets are relative to their ship's node, so keep each waypoint
+/// under the same node entity as its ship
+pub fn attach_waypoints_system(
+  mut commands: Commands,
+  entity_mapping: Res<EntityMapping>,
+  ships: Query<&ChildOf, Without<WaypointMarker>>,
+  waypoints: Query<(Entity, &WaypointMarker, Option<&ChildOf>)>,
+) {
+  for (bevy_entity, marker, child_of) in waypoints.iter() {
+    let parent = entity_mapping.get_bevy_entity(marker.entity_id)
+      .and_then(|ship| ships.get(ship).ok())
+      .map(|child_of| child_of.parent());
+    if parent == child_of.map(|child_of| child_of.parent()) {
+      continue;
+    }
+    match parent {
+      Some(parent) => {
+        commands.entity(bevy_entity).insert(ChildOf(parent));
+      },
+      None => {
+        commands.entity(bevy_entity).remove::<ChildOf>();
+      },
     }
   }
-}
\ No newline at end of file
+}
@@ -5,6 +5,8 @@ pub mod ui_input;
 pub mod ui_focus;
 pub mod ui_window;
 pub mod ui_sandbox;
+pub mod ui_connection_status;
+pub mod ui_network_diagnostics;
 
 use bevy::{
   prelude::*,
@@ -26,6 +28,12 @@ use ui_focus::{
 };
 use ui_window::WindowPlugin;
 use ui_sandbox::ui_sandbox;
+use ui_connection_status::{spawn_connection_status, connection_status_system};
+use ui_network_diagnostics::{
+  spawn_network_diagnostics,
+  toggle_network_diagnostics_system,
+  network_diagnostics_system,
+};
 
 
 pub fn ui_plugin(app: &mut App) {
@@ -37,12 +45,14 @@ pub fn ui_plugin(app: &mut App) {
     ))
     .init_resource::<Theme>()
     .init_resource::<InputFocus>()
-    .add_systems(Startup, ui_sandbox)
+    .add_systems(Startup, (ui_sandbox, spawn_connection_status, spawn_network_diagnostics))
     .add_systems(Update, (
       button_system,
       checkbox_system,
       text_input_system,
       text_input_click_system,
       focus_system,
+      connection_status_system,
+      (toggle_network_diagnostics_system, network_diagnostics_system).chain(),
     ));
 }
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use super::ui_theme::Theme;
+use crate::synchronizer::ConnectionStatus;
+
+/// Marks the text showing the server connection state
+#[derive(Component)]
+pub struct ConnectionStatusText;
+
+pub fn spawn_connection_status(
+  mut commands: Commands,
+  theme: Res<Theme>,
+) {
+  commands.spawn((
+    Node {
+      position_type: PositionType::Absolute,
+      top: Val::Px(8.0),
+      right: Val::Px(8.0),
+      padding: UiRect::all(theme.padding),
+      ..default()
+    },
+    BackgroundColor(theme.color_default),
+    children![(
+      Text::new("Connecting..."),
+      TextFont {
+        font_size: 14.0,
+        ..default()
+      },
+      TextColor(theme.color_secondary_lightest),
+      ConnectionStatusText,
+    )],
+  ));
+}
+
+pub fn connection_status_system(
+  status: Res<ConnectionStatus>,
+  theme: Res<Theme>,
+  mut query: Query<(&mut Text, &mut TextColor), With<ConnectionStatusText>>,
+) {
+  if !status.is_changed() {
+    return;
+  }
+
+  let (label, color) = match &*status {
+    ConnectionStatus::Connecting { attempt: 0 } => ("Connecting...".to_string(), theme.color_secondary_lightest),
+    ConnectionStatus::Connecting { attempt } => (format!("Reconnecting (attempt {})...", attempt + 1), theme.color_secondary_lightest),
+    ConnectionStatus::Online => ("Online".to_string(), Color::srgb(0.5, 0.9, 0.5)),
+    ConnectionStatus::Offline { reason, retry_in } => (
+      format!("Offline: {} (retrying in {}s)", reason, retry_in.as_secs()),
+      Color::srgb(0.9, 0.4, 0.4),
+    ),
+  };
+
+  for (mut text, mut text_color) in &mut query {
+    text.0 = label.clone();
+    text_color.0 = color;
+  }
+}
@@ -0,0 +1,97 @@
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::prelude::*;
+use super::ui_theme::Theme;
+use crate::components::Selected;
+use crate::synchronizer::ConnectionStatus;
+use crate::synchronizer::diagnostics::{ROWS, ROW_EVENTS, SELECTED_UPDATE_INTERVAL, REDUCER_ROUND_TRIP};
+
+/// Shows or hides the overlay
+const TOGGLE_KEY: KeyCode = KeyCode::F3;
+
+/// Marks the network diagnostics overlay
+#[derive(Component)]
+pub struct NetworkDiagnosticsOverlay;
+
+/// Marks the text inside the overlay
+#[derive(Component)]
+pub struct NetworkDiagnosticsText;
+
+pub fn spawn_network_diagnostics(
+  mut commands: Commands,
+  theme: Res<Theme>,
+) {
+  commands.spawn((
+    Node {
+      position_type: PositionType::Absolute,
+      top: Val::Px(40.0),
+      right: Val::Px(8.0),
+      padding: UiRect::all(theme.padding),
+      ..default()
+    },
+    BackgroundColor(theme.color_default),
+    Visibility::Hidden,
+    NetworkDiagnosticsOverlay,
+    children![(
+      Text::new(""),
+      TextFont {
+        font_size: 12.0,
+        ..default()
+      },
+      TextColor(theme.color_secondary_lightest),
+      NetworkDiagnosticsText,
+    )],
+  ));
+}
+
+pub fn toggle_network_diagnostics_system(
+  keys: Res<ButtonInput<KeyCode>>,
+  mut query: Query<&mut Visibility, With<NetworkDiagnosticsOverlay>>,
+) {
+  if !keys.just_pressed(TOGGLE_KEY) {
+    return;
+  }
+  for mut visibility in &mut query {
+    visibility.toggle_visible_hidden();
+  }
+}
+
+/// Latest smoothed value of a diagnostic, formatted with its suffix
+fn smoothed(store: &DiagnosticsStore, path: &DiagnosticPath, precision: usize) -> String {
+  store.get(path)
+    .and_then(|diagnostic| diagnostic.smoothed().map(|value| format!("{:.*}{}", precision, value, diagnostic.suffix)))
+    .unwrap_or_else(|| "-".to_string())
+}
+
+pub fn network_diagnostics_system(
+  store: Res<DiagnosticsStore>,
+  status: Res<ConnectionStatus>,
+  selected: Query<(), With<Selected>>,
+  overlay: Query<&Visibility, With<NetworkDiagnosticsOverlay>>,
+  mut query: Query<&mut Text, With<NetworkDiagnosticsText>>,
+) {
+  if overlay.iter().all(|visibility| *visibility == Visibility::Hidden) {
+    return;
+  }
+
+  let connection = match &*status {
+    ConnectionStatus::Connecting { attempt } => format!("connecting (attempt {})", attempt + 1),
+    ConnectionStatus::Online => "online".to_string(),
+    ConnectionStatus::Offline { reason, .. } => format!("offline: {}", reason),
+  };
+
+  let mut lines = vec![format!("Connection: {}", connection)];
+  for (table, path) in ROWS.iter() {
+    lines.push(format!("  {}: {}", table, smoothed(&store, path, 0)));
+  }
+  lines.push(format!("Row events: {}", smoothed(&store, &ROW_EVENTS, 1)));
+  lines.push(if selected.is_empty() {
+    "Selected update interval: no entity selected".to_string()
+  } else {
+    format!("Selected update interval: {}", smoothed(&store, &SELECTED_UPDATE_INTERVAL, 0))
+  });
+  lines.push(format!("Reducer round trip: {}", smoothed(&store, &REDUCER_ROUND_TRIP, 0)));
+
+  for mut text in &mut query {
+    text.0 = lines.join("\n");
+  }
+}
@@ -0,0 +1,11 @@
+#!/usr/bin/env sh
+# Regenerate the client's SpacetimeDB bindings from the server module.
+# Run after changing any table, type or reducer under server/, and commit the
+# result together with the module change.
+set -e
+
+cd "$(dirname "$0")/.."
+spacetime generate --lang rust \
+  --project-path server \
+  --out-dir client/src/spacetime_bindings \
+  --yes
@@ -0,0 +1,32 @@
+//! Identities allowed to run world-altering reducers.
+
+use spacetimedb::{reducer, table, Identity, ReducerContext, Table};
+
+#[table(name = admin, public)]
+pub struct Admin {
+  #[primary_key]
+  identity: Identity,
+}
+
+/// Make whoever published the module the first admin. Called from `init`.
+pub fn init(ctx: &ReducerContext) {
+  ctx.db.admin().insert(Admin { identity: ctx.sender });
+}
+
+/// Fail unless the caller is an admin
+pub fn require(ctx: &ReducerContext) -> Result<(), String> {
+  match ctx.db.admin().identity().find(&ctx.sender) {
+    Some(_) => Ok(()),
+    None => Err("Only admins can do that".to_string()),
+  }
+}
+
+#[reducer]
+pub fn grant_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+  require(ctx)?;
+  if ctx.db.admin().identity().find(&identity).is_none() {
+    ctx.db.admin().insert(Admin { identity });
+    log::info!("Granted admin to {}", identity);
+  }
+  Ok(())
+}
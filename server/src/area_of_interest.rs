@@ -0,0 +1,63 @@
+//! Which cell of which node every entity is in, so clients can subscribe to
+//! the entities around them by joining against this table.
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{table, ReducerContext, Table};
+
+use shared::area_of_interest::Cell;
+
+use crate::{entity, node};
+
+#[table(name = entity_cell, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct EntityCell {
+  #[primary_key]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  #[index(btree)]
+  pub(crate) node_id: u64, // Foreign key to Node table
+  #[index(btree)]
+  x: i64,
+  #[index(btree)]
+  y: i64,
+  #[index(btree)]
+  z: i64,
+}
+
+/// Bring every entity's cell up to date. Rows only change when an entity
+/// crosses into another cell, so subscribers aren't re-sent every tick.
+pub fn refresh(ctx: &ReducerContext) {
+  let nodes: Vec<_> = ctx.db.node().iter().map(|node| (node.name, node.id)).collect();
+
+  for entity in ctx.db.entity().iter() {
+    let Some(&(_, node_id)) = nodes.iter().find(|(name, _)| *name == entity.node) else {
+      continue;
+    };
+    let cell = Cell::containing(entity.relative_position);
+    let row = EntityCell {
+      entity_id: entity.id,
+      node_id,
+      x: cell.x,
+      y: cell.y,
+      z: cell.z,
+    };
+
+    match ctx.db.entity_cell().entity_id().find(&entity.id) {
+      Some(current) if current.node_id == row.node_id && current.x == row.x && current.y == row.y && current.z == row.z => {},
+      Some(_) => {
+        ctx.db.entity_cell().entity_id().update(row);
+      },
+      None => {
+        ctx.db.entity_cell().insert(row);
+      },
+    }
+  }
+
+  // Entities that are gone
+  let stale: Vec<u64> = ctx.db.entity_cell().iter()
+    .filter(|cell| ctx.db.entity().id().find(&cell.entity_id).is_none())
+    .map(|cell| cell.entity_id)
+    .collect();
+  for entity_id in stale {
+    ctx.db.entity_cell().entity_id().delete(&entity_id);
+  }
+}
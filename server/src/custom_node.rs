@@ -0,0 +1,120 @@
+//! Admin-made reference frames such as stations, Lagrange-point markers and
+//! asteroid bases. Catalogued bodies are seeded and can't be changed here.
+
+use spacetimedb::{reducer, ReducerContext, Table};
+
+use crate::admin;
+use crate::ids;
+use crate::{entity, find_node, node, DQuat, DVec3, Node, NodeName};
+
+/// The node named `name`, which has to be one made with `create_node`
+fn find_custom(ctx: &ReducerContext, name: &str) -> Result<Node, String> {
+  let name = NodeName::Custom(name.to_string());
+  find_node(ctx, &name).ok_or_else(|| format!("Custom node {} not found", name))
+}
+
+/// Id of the parent frame, failing if it doesn't exist
+fn parent_id(ctx: &ReducerContext, parent: &Option<NodeName>) -> Result<Option<u64>, String> {
+  match parent {
+    Some(parent) => find_node(ctx, parent)
+      .map(|node| Some(node.id))
+      .ok_or_else(|| format!("Parent node {} not found", parent)),
+    None => Ok(None),
+  }
+}
+
+#[reducer]
+pub fn create_node(
+  ctx: &ReducerContext,
+  name: String,
+  parent: Option<NodeName>,
+  position: DVec3,
+  velocity: DVec3,
+  mass: f64,
+  radius: f64,
+) -> Result<(), String> {
+  admin::require(ctx)?;
+  if name.trim().is_empty() {
+    return Err("Node name cannot be empty".to_string());
+  }
+  if !matches!(name.parse::<NodeName>()?, NodeName::Custom(_)) {
+    return Err(format!("{} is a catalogued body and cannot be created", name));
+  }
+  let name = NodeName::Custom(name);
+  if find_node(ctx, &name).is_some() {
+    return Err(format!("Node {} already exists", name));
+  }
+  if !position.is_finite() || !velocity.is_finite() || !mass.is_finite() || !radius.is_finite() {
+    return Err("Position, velocity, mass and radius must be finite".to_string());
+  }
+  if mass < 0.0 || radius < 0.0 {
+    return Err("Mass and radius cannot be negative".to_string());
+  }
+
+  let node = ctx.db.node().insert(Node {
+    id: ids::next(ctx, "node"),
+    name,
+    parent_id: parent_id(ctx, &parent)?,
+    node_position: position,
+    node_velocity: velocity,
+    node_rotation: DQuat::IDENTITY,
+    node_rotational_velocity: DVec3::ZERO,
+    orbit_progress: 0.0,
+    mass,
+    radius,
+  });
+  log::info!("Created node {} (ID: {})", node.name, node.id);
+  Ok(())
+}
+
+/// Place a custom node somewhere else, optionally in another parent frame.
+/// Entities in its frame move along with it.
+#[reducer]
+pub fn move_node(
+  ctx: &ReducerContext,
+  name: String,
+  parent: Option<NodeName>,
+  position: DVec3,
+  velocity: DVec3,
+) -> Result<(), String> {
+  admin::require(ctx)?;
+  if !position.is_finite() || !velocity.is_finite() {
+    return Err("Position and velocity must be finite".to_string());
+  }
+  let mut node = find_custom(ctx, &name)?;
+  let parent_id = parent_id(ctx, &parent)?;
+
+  // Walk up from the new parent - meeting the node itself would make a loop
+  let mut ancestor = parent_id;
+  while let Some(id) = ancestor {
+    if id == node.id {
+      return Err(format!("Node {} cannot be placed inside itself", node.name));
+    }
+    ancestor = ctx.db.node().id().find(&id).and_then(|ancestor| ancestor.parent_id);
+  }
+
+  node.parent_id = parent_id;
+  node.node_position = position;
+  node.node_velocity = velocity;
+  log::info!("Moved node {}", node.name);
+  ctx.db.node().id().update(node);
+  Ok(())
+}
+
+/// Remove an empty custom node
+#[reducer]
+pub fn delete_node(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  let node = find_custom(ctx, &name)?;
+
+  if ctx.db.node().parent_id().filter(&Some(node.id)).next().is_some() {
+    return Err(format!("Node {} still has child nodes", node.name));
+  }
+  if ctx.db.entity().node().filter(&node.name).next().is_some() {
+    return Err(format!("Node {} still has entities in its frame", node.name));
+  }
+
+  ctx.db.node().id().delete(&node.id);
+  log::info!("Deleted node {}", node.name);
+  Ok(())
+}
@@ -0,0 +1,108 @@
+//! Bridge between `entity` rows and the trajectory solver.
+//!
+//! The row/solver conversion itself lives with `FlightState` in the shared
+//! crate, so clients can step ships exactly like the server does.
+
+use spacetimedb::{ReducerContext, Table};
+
+use crate::formation::{formation, formation_member};
+use crate::settings;
+pub use crate::trajectory::FlightState;
+use crate::trajectory::{self, FlightMode, LeaderState};
+use crate::{entity, find_node, waypoint, DVec3, Entity, NodeName};
+
+/// Conversions between `entity` rows and the solver's state
+pub trait EntityState {
+  fn from_entity(entity: &Entity) -> Self;
+  fn write_to(&self, entity: &mut Entity);
+}
+
+impl EntityState for FlightState {
+  fn from_entity(entity: &Entity) -> Self {
+    FlightState::from_pose(
+      entity.relative_position,
+      entity.relative_velocity,
+      entity.relative_rotation,
+      entity.relative_rotational_velocity,
+    )
+  }
+
+  fn write_to(&self, entity: &mut Entity) {
+    entity.relative_position = self.position;
+    entity.relative_velocity = self.velocity;
+    entity.relative_rotation = self.orientation();
+    entity.relative_rotational_velocity = self.angular_velocity();
+  }
+}
+
+/// Gravitational parameter (μ = GM) of the body a node represents
+pub fn gravitational_parameter(ctx: &ReducerContext, node_name: &NodeName) -> f64 {
+  find_node(ctx, node_name)
+    .map(|node| trajectory::GRAVITATIONAL_CONSTANT * node.mass)
+    .unwrap_or(0.0)
+}
+
+/// How the tick moves an entity
+pub enum Control {
+  /// Held in a formation slot by the trajectory solver, with its leader
+  Formation { flight_mode: FlightMode, leader: Option<LeaderState> },
+  /// Flown through these points in order by `shared::autopilot`
+  Waypoints(Vec<DVec3>),
+  /// Left to fall around its node's body
+  Coast,
+}
+
+/// Whether the tick flies the entity through its waypoints
+pub fn follows_waypoints(entity: &Entity) -> bool {
+  entity.designation == "TestShip"
+}
+
+/// How the tick is moving an entity. Maneuvers and pilots aren't covered -
+/// a ship under either counts as coasting.
+pub fn current_control(ctx: &ReducerContext, entity: &Entity) -> Control {
+  if let Some(member) = ctx.db.formation_member().entity_id().find(&entity.id) {
+    let leader = ctx.db.formation().id().find(&member.formation_id)
+      .and_then(|formation| ctx.db.entity().id().find(&formation.leader_id))
+      .map(|leader| FlightState::from_entity(&leader).as_leader());
+    let flight_mode = FlightMode::Formation { offset: member.offset };
+    return Control::Formation { flight_mode, leader };
+  }
+
+  if follows_waypoints(entity) {
+    let mut waypoints: Vec<_> = ctx.db.waypoint().entity_id().filter(&entity.id).collect();
+    waypoints.sort_by_key(|waypoint| waypoint.order_index);
+    if !waypoints.is_empty() {
+      return Control::Waypoints(waypoints.into_iter().map(|waypoint| waypoint.target_position).collect());
+    }
+  }
+  Control::Coast
+}
+
+/// Advance an entity by one solver step under the given flight mode
+pub fn fly(ctx: &ReducerContext, entity: &mut Entity, flight_mode: FlightMode, leader: Option<LeaderState>) {
+  let mut state = FlightState::from_entity(entity);
+  let gravitational_parameter = gravitational_parameter(ctx, &entity.node);
+
+  state.step(
+    flight_mode,
+    leader,
+    settings::integrator(ctx),
+    gravitational_parameter,
+    entity.mass,
+    entity.max_impulse,
+  );
+
+  state.write_to(entity);
+}
+
+/// Advance an entity by one step with no thrust, falling around its node's body
+pub fn coast(ctx: &ReducerContext, entity: &mut Entity) {
+  trajectory::integrate_translation(
+    settings::integrator(ctx),
+    DVec3::ZERO,
+    gravitational_parameter(ctx, &entity.node),
+    &mut entity.relative_position,
+    &mut entity.relative_velocity,
+    trajectory::DELTA_TIME,
+  );
+}
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table};
+
+use crate::flight::{self, EntityState, FlightState};
+use crate::ids;
+use crate::maneuver;
+use crate::piloting;
+use crate::trajectory::FlightMode;
+use crate::{entity, DVec3, EntityType};
+
+#[table(name = formation, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Formation {
+  #[primary_key]
+  pub(crate) id: u64,
+  #[unique]
+  pub(crate) name: String,
+  #[unique]
+  pub(crate) leader_id: u64, // Foreign key to Entity table
+}
+
+#[table(name = formation_member, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FormationMember {
+  #[primary_key]
+  pub(crate) id: u64,
+  #[index(btree)]
+  pub(crate) formation_id: u64, // Foreign key to Formation table
+  #[unique]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  offset: DVec3, // Slot in the leader's body frame: forward (x), up (y), right (z)
+}
+
+/// Whether the entity is currently flying as a formation follower
+pub fn is_following(ctx: &ReducerContext, entity_id: u64) -> bool {
+  ctx.db.formation_member().entity_id().find(&entity_id).is_some()
+}
+
+/// Step every follower toward its slot. Called once per tick.
+pub fn fly_formations(ctx: &ReducerContext) {
+  let formations: Vec<Formation> = ctx.db.formation().iter().collect();
+  for formation in formations {
+    let leader = match ctx.db.entity().id().find(&formation.leader_id) {
+      Some(leader) => leader,
+      None => {
+        // Nobody left to follow - release the followers rather than hold a stale slot
+        log::warn!("Leader {} of formation {} is gone, disbanding it", formation.leader_id, formation.name);
+        disband(ctx, &formation);
+        continue;
+      }
+    };
+    let leader_state = FlightState::from_entity(&leader).as_leader();
+
+    let members: Vec<FormationMember> = ctx.db.formation_member().formation_id().filter(&formation.id).collect();
+    for member in members {
+      let mut follower = match ctx.db.entity().id().find(&member.entity_id) {
+        Some(follower) => follower,
+        None => {
+          log::warn!("Member {} of formation {} is gone, removing it", member.entity_id, formation.name);
+          ctx.db.formation_member().id().delete(&member.id);
+          continue;
+        }
+      };
+      // Positions are relative to the node - a slot across frames means nothing
+      if follower.node != leader.node {
+        log::warn!("Entity {} left the node of formation {}, removing it", follower.id, formation.name);
+        ctx.db.formation_member().id().delete(&member.id);
+        continue;
+      }
+
+      let flight_mode = FlightMode::Formation { offset: member.offset };
+      flight::fly(ctx, &mut follower, flight_mode, Some(leader_state));
+      ctx.db.entity().id().update(follower);
+    }
+  }
+}
+
+/// Remove a formation and release all its members
+fn disband(ctx: &ReducerContext, formation: &Formation) {
+  let members: Vec<FormationMember> = ctx.db.formation_member().formation_id().filter(&formation.id).collect();
+  for member in members {
+    ctx.db.formation_member().id().delete(&member.id);
+  }
+  ctx.db.formation().id().delete(&formation.id);
+}
+
+#[reducer]
+pub fn create_formation(ctx: &ReducerContext, name: String, leader_id: u64) -> Result<(), String> {
+  if name.trim().is_empty() {
+    return Err("Formation name must not be empty".to_string());
+  }
+  if ctx.db.formation().name().find(&name).is_some() {
+    return Err(format!("Formation {} already exists", name));
+  }
+  if ctx.db.entity().id().find(&leader_id).is_none() {
+    return Err(format!("Leader entity {} not found", leader_id));
+  }
+  piloting::require_pilot_or_admin(ctx, leader_id)?;
+  if ctx.db.formation().leader_id().find(&leader_id).is_some() {
+    return Err(format!("Entity {} already leads a formation", leader_id));
+  }
+  if is_following(ctx, leader_id) {
+    return Err(format!("Entity {} is already flying in a formation", leader_id));
+  }
+
+  ctx.db.formation().insert(Formation {
+    id: ids::next(ctx, "formation"),
+    name: name.clone(),
+    leader_id,
+  });
+  log::info!("Created formation {} led by entity {}", name, leader_id);
+  Ok(())
+}
+
+#[reducer]
+pub fn join_formation(ctx: &ReducerContext, name: String, entity_id: u64, offset: DVec3) -> Result<(), String> {
+  let formation = match ctx.db.formation().name().find(&name) {
+    Some(formation) => formation,
+    None => return Err(format!("Formation {} not found", name)),
+  };
+  let follower = match ctx.db.entity().id().find(&entity_id) {
+    Some(follower) => follower,
+    None => return Err(format!("Entity {} not found", entity_id)),
+  };
+  piloting::require_pilot_or_admin(ctx, entity_id)?;
+  let leader = match ctx.db.entity().id().find(&formation.leader_id) {
+    Some(leader) => leader,
+    None => return Err(format!("Leader of formation {} no longer exists", name)),
+  };
+
+  if !matches!(follower.entity_type, EntityType::Ship) {
+    return Err(format!("Entity {} is not a ship", entity_id));
+  }
+  if ctx.db.formation().leader_id().find(&entity_id).is_some() {
+    return Err(format!("Entity {} leads a formation and cannot follow", entity_id));
+  }
+  if is_following(ctx, entity_id) {
+    return Err(format!("Entity {} is already flying in a formation", entity_id));
+  }
+  if piloting::is_piloted(ctx, entity_id) {
+    return Err(format!("Entity {} is piloted", entity_id));
+  }
+  // The formation would steer it away from its burns
+  if maneuver::is_pending(ctx, entity_id) {
+    return Err(format!("Entity {} has a maneuver planned or in progress", entity_id));
+  }
+  // Positions are relative to the node, so both ships must share a frame
+  if follower.node != leader.node {
+    return Err(format!("Entity {} is not in the same node as the leader", entity_id));
+  }
+
+  ctx.db.formation_member().insert(FormationMember {
+    id: ids::next(ctx, "formation_member"),
+    formation_id: formation.id,
+    entity_id,
+    offset,
+  });
+  log::info!("Entity {} joined formation {}", entity_id, name);
+  Ok(())
+}
+
+#[reducer]
+pub fn leave_formation(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+  piloting::require_pilot_or_admin(ctx, entity_id)?;
+  if ctx.db.formation_member().entity_id().delete(&entity_id) {
+    log::info!("Entity {} left its formation", entity_id);
+    Ok(())
+  } else {
+    Err(format!("Entity {} is not in a formation", entity_id))
+  }
+}
+
+#[reducer]
+pub fn disband_formation(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  let formation = match ctx.db.formation().name().find(&name) {
+    Some(formation) => formation,
+    None => return Err(format!("Formation {} not found", name)),
+  };
+  piloting::require_pilot_or_admin(ctx, formation.leader_id)?;
+
+  disband(ctx, &formation);
+  log::info!("Disbanded formation {}", name);
+  Ok(())
+}
@@ -0,0 +1,69 @@
+//! Row ids for the game tables.
+//!
+//! A snapshot restore puts rows back under the ids in its document, and a
+//! module can't move an auto_inc sequence past them. So the game tables
+//! don't use auto_inc: every new row takes its id from a counter per table,
+//! and a restore lifts each counter above the ids it brought back.
+
+use spacetimedb::{table, ReducerContext, Table};
+
+use crate::formation::{formation, formation_member};
+use crate::maneuver::maneuver;
+use crate::piloting::pilot_input;
+use crate::ship_class::ship_class;
+use crate::transfer::transfer_plan;
+use crate::{entity, node, waypoint};
+
+/// Highest id handed out so far for one table
+#[table(name = id_counter)]
+pub struct IdCounter {
+  #[primary_key]
+  table: String, // Table name as in its `#[table(name = ...)]`
+  last: u64,
+}
+
+/// A fresh id for a new row of `table`
+pub fn next(ctx: &ReducerContext, table: &str) -> u64 {
+  let id = last(ctx, table) + 1;
+  set_last(ctx, table, id);
+  id
+}
+
+/// Make sure no id up to `id` is handed out again for `table`
+pub fn reserve(ctx: &ReducerContext, table: &str, id: u64) {
+  if id > last(ctx, table) {
+    set_last(ctx, table, id);
+  }
+}
+
+/// Lift every counter above the rows already in its table. Run as a
+/// migration for worlds whose rows came from auto_inc sequences, and after
+/// a restore.
+pub fn reserve_existing(ctx: &ReducerContext) -> Result<(), String> {
+  reserve(ctx, "ship_class", ctx.db.ship_class().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "node", ctx.db.node().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "entity", ctx.db.entity().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "waypoint", ctx.db.waypoint().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "formation", ctx.db.formation().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "formation_member", ctx.db.formation_member().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "maneuver", ctx.db.maneuver().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "transfer_plan", ctx.db.transfer_plan().iter().map(|row| row.id).max().unwrap_or(0));
+  reserve(ctx, "pilot_input", ctx.db.pilot_input().iter().map(|row| row.id).max().unwrap_or(0));
+  Ok(())
+}
+
+fn last(ctx: &ReducerContext, table: &str) -> u64 {
+  ctx.db.id_counter().table().find(&table.to_string()).map(|counter| counter.last).unwrap_or(0)
+}
+
+fn set_last(ctx: &ReducerContext, table: &str, last: u64) {
+  let counter = IdCounter {
+    table: table.to_string(),
+    last,
+  };
+  if ctx.db.id_counter().table().find(&counter.table).is_some() {
+    ctx.db.id_counter().table().update(counter);
+  } else {
+    ctx.db.id_counter().insert(counter);
+  }
+}
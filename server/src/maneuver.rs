@@ -0,0 +1,350 @@
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::flight;
+use crate::formation;
+use crate::ids;
+use crate::migration;
+use crate::piloting;
+use crate::settings;
+use crate::ship_class;
+use crate::trajectory::{self, Integrator};
+use crate::{entity, DQuat, DVec3, Entity, EntityType};
+
+const ORIENTATION_LEAD_MICROS: i64 = 30_000_000; // Start turning 30s before the burn
+const LATE_TOLERANCE_MICROS: i64 = 10_000_000; // Give up if still not aligned 10s after
+const ALIGNMENT_TOLERANCE: f64 = 0.044; // ±2.5 degrees in radians
+const DELTA_V_TOLERANCE: f64 = 0.01; // m/s left over that counts as done
+pub const ORIENTATION_LEAD: f64 = ORIENTATION_LEAD_MICROS as f64 / 1_000_000.0; // seconds
+pub const STANDARD_GRAVITY: f64 = 9.80665; // m/s², converts specific impulse to exhaust velocity
+
+/// Frame the delta-v of a maneuver is expressed in
+#[derive(SpacetimeType, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum ManeuverFrame {
+  /// Node frame: x, y, z as stored on the entity
+  World,
+  /// Orbit frame around the node: prograde (x), normal (y), radial out (z)
+  Orbital,
+}
+
+#[derive(SpacetimeType, Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum ManeuverStatus {
+  Planned,
+  Orienting,
+  Burning,
+  Completed,
+  Failed(String),
+}
+
+#[table(name = maneuver, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Maneuver {
+  #[primary_key]
+  pub(crate) id: u64,
+  #[index(btree)]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  #[serde(with = "crate::snapshot::timestamp")]
+  execute_at: Timestamp,
+  delta_v: DVec3, // m/s in `frame`
+  frame: ManeuverFrame,
+  status: ManeuverStatus,
+  burn_direction: DVec3, // Node frame, fixed once the ship starts orienting
+  delta_v_remaining: f64, // m/s still to burn
+  #[serde(with = "crate::snapshot::optional_timestamp")]
+  finished_at: Option<Timestamp>,
+}
+
+#[table(name = maneuver_schedule, scheduled(execute_maneuvers))]
+pub struct ManeuverSchedule {
+  #[primary_key]
+  #[auto_inc]
+  scheduled_id: u64,
+
+  scheduled_at: ScheduleAt,
+}
+
+/// Start the maneuver executor. Called from `init`.
+pub fn schedule(ctx: &ReducerContext) {
+  ctx.db.maneuver_schedule().insert(ManeuverSchedule {
+    scheduled_id: 0,
+    scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(50_000)),
+  });
+}
+
+/// Whether the entity is currently turning for or performing a burn
+pub fn is_active(ctx: &ReducerContext, entity_id: u64) -> bool {
+  ctx.db.maneuver().entity_id().filter(&entity_id)
+    .any(|maneuver| matches!(maneuver.status, ManeuverStatus::Orienting | ManeuverStatus::Burning))
+}
+
+/// Whether the entity has a burn that hasn't finished, including planned ones
+pub fn is_pending(ctx: &ReducerContext, entity_id: u64) -> bool {
+  ctx.db.maneuver().entity_id().filter(&entity_id)
+    .any(|maneuver| matches!(maneuver.status, ManeuverStatus::Planned | ManeuverStatus::Orienting | ManeuverStatus::Burning))
+}
+
+#[reducer]
+pub fn plan_maneuver(
+  ctx: &ReducerContext,
+  entity_id: u64,
+  execute_at: Timestamp,
+  delta_v: DVec3,
+  frame: ManeuverFrame,
+) -> Result<(), String> {
+  piloting::require_pilot_or_admin(ctx, entity_id)?;
+  schedule_burn(ctx, entity_id, execute_at, delta_v, frame).map(|_| ())
+}
+
+/// Validate and insert a planned burn, returning the new maneuver id
+pub fn schedule_burn(
+  ctx: &ReducerContext,
+  entity_id: u64,
+  execute_at: Timestamp,
+  delta_v: DVec3,
+  frame: ManeuverFrame,
+) -> Result<u64, String> {
+  let ship = match ctx.db.entity().id().find(&entity_id) {
+    Some(ship) => ship,
+    None => return Err(format!("Entity {} not found", entity_id)),
+  };
+  if ship.entity_type != EntityType::Ship {
+    return Err(format!("Entity {} is not a ship", entity_id));
+  }
+  if formation::is_following(ctx, entity_id) {
+    return Err(format!("Entity {} is flying in formation", entity_id));
+  }
+  if piloting::is_piloted(ctx, entity_id) {
+    return Err(format!("Entity {} is piloted", entity_id));
+  }
+  if execute_at.to_micros_since_unix_epoch() <= ctx.timestamp.to_micros_since_unix_epoch() {
+    return Err("Maneuver must be scheduled in the future".to_string());
+  }
+  if !delta_v.is_finite() {
+    return Err("Maneuver delta-v must be finite".to_string());
+  }
+  let magnitude = delta_v.length();
+  if magnitude < DELTA_V_TOLERANCE {
+    return Err("Maneuver delta-v is too small".to_string());
+  }
+  if frame == ManeuverFrame::Orbital && orbital_frame(&ship).is_none() {
+    return Err(format!("Entity {} has no orbit to burn relative to", entity_id));
+  }
+
+  let maneuver = ctx.db.maneuver().insert(Maneuver {
+    id: ids::next(ctx, "maneuver"),
+    entity_id,
+    execute_at,
+    delta_v,
+    frame,
+    status: ManeuverStatus::Planned,
+    burn_direction: DVec3 { x: 0.0, y: 0.0, z: 0.0 },
+    delta_v_remaining: magnitude,
+    finished_at: None,
+  });
+  log::info!("Planned {:.2} m/s maneuver for entity {}", magnitude, entity_id);
+  Ok(maneuver.id)
+}
+
+#[reducer]
+pub fn cancel_maneuver(ctx: &ReducerContext, maneuver_id: u64) -> Result<(), String> {
+  let maneuver = match ctx.db.maneuver().id().find(&maneuver_id) {
+    Some(maneuver) => maneuver,
+    None => return Err(format!("Maneuver {} not found", maneuver_id)),
+  };
+  if matches!(maneuver.status, ManeuverStatus::Completed | ManeuverStatus::Failed(_)) {
+    return Err(format!("Maneuver {} has already finished", maneuver_id));
+  }
+  piloting::require_pilot_or_admin(ctx, maneuver.entity_id)?;
+
+  ctx.db.maneuver().id().delete(&maneuver_id);
+  log::info!("Cancelled maneuver {}", maneuver_id);
+  Ok(())
+}
+
+#[reducer]
+fn execute_maneuvers(ctx: &ReducerContext, _arg: ManeuverSchedule) {
+  if migration::is_blocked(ctx) {
+    return;
+  }
+  let now = ctx.timestamp.to_micros_since_unix_epoch();
+  let dt = trajectory::DELTA_TIME;
+  let integrator = settings::integrator(ctx);
+
+  let mut pending: Vec<Maneuver> = ctx.db.maneuver().iter()
+    .filter(|maneuver| matches!(
+      maneuver.status,
+      ManeuverStatus::Planned | ManeuverStatus::Orienting | ManeuverStatus::Burning,
+    ))
+    .collect();
+  pending.sort_by_key(|maneuver| maneuver.execute_at.to_micros_since_unix_epoch());
+
+  // A ship only works on its earliest maneuver at a time
+  let mut busy_ships = Vec::new();
+
+  for mut maneuver in pending {
+    if busy_ships.contains(&maneuver.entity_id) {
+      continue;
+    }
+    let execute_at = maneuver.execute_at.to_micros_since_unix_epoch();
+    if now < execute_at - ORIENTATION_LEAD_MICROS {
+      continue;
+    }
+    busy_ships.push(maneuver.entity_id);
+
+    let mut ship = match ctx.db.entity().id().find(&maneuver.entity_id) {
+      Some(ship) => ship,
+      None => {
+        finish(ctx, maneuver, ManeuverStatus::Failed("Ship no longer exists".to_string()));
+        continue;
+      }
+    };
+
+    if maneuver.status == ManeuverStatus::Planned {
+      // Lock in the burn direction before the ship starts turning
+      let Some(direction) = burn_direction(&ship, &maneuver) else {
+        finish(ctx, maneuver, ManeuverStatus::Failed("Orbit frame is undefined".to_string()));
+        continue;
+      };
+      maneuver.burn_direction = direction;
+      maneuver.status = ManeuverStatus::Orienting;
+      log::info!("Maneuver {} orienting ship {}", maneuver.id, ship.designation);
+    }
+
+    let moment_of_inertia = ship_class::moment_of_inertia(ctx, &ship);
+    let aligned = slew_toward(&mut ship, &maneuver.burn_direction, moment_of_inertia, dt);
+    let gravitational_parameter = flight::gravitational_parameter(ctx, &ship.node);
+
+    let mut thrust_acceleration = 0.0;
+    if maneuver.status == ManeuverStatus::Orienting {
+      if aligned && now >= execute_at {
+        maneuver.status = ManeuverStatus::Burning;
+        log::info!("Maneuver {} burning, {:.2} m/s to go", maneuver.id, maneuver.delta_v_remaining);
+      } else if now > execute_at + LATE_TOLERANCE_MICROS {
+        coast(&mut ship, integrator, gravitational_parameter, 0.0, &maneuver.burn_direction, dt);
+        ctx.db.entity().id().update(ship);
+        finish(ctx, maneuver, ManeuverStatus::Failed("Could not orient in time".to_string()));
+        continue;
+      }
+    }
+
+    let mut status = None;
+    if maneuver.status == ManeuverStatus::Burning {
+      let main_thrust = ship.max_impulse.x;
+      let full_step = main_thrust / ship.mass * dt;
+      if full_step <= 0.0 {
+        status = Some(ManeuverStatus::Failed("Ship has no main thrust".to_string()));
+      } else {
+        // Throttle down for the last sliver of delta-v
+        let mut throttle = (maneuver.delta_v_remaining / full_step).min(1.0);
+        let exhaust_velocity = ship.specific_impulse * STANDARD_GRAVITY;
+        let mut propellant_used = main_thrust * throttle * dt / exhaust_velocity;
+        if propellant_used > ship.propellant {
+          throttle *= ship.propellant / propellant_used;
+          propellant_used = ship.propellant;
+          status = Some(ManeuverStatus::Failed("Out of propellant".to_string()));
+        }
+
+        thrust_acceleration = main_thrust * throttle / ship.mass;
+        maneuver.delta_v_remaining -= thrust_acceleration * dt;
+        ship.propellant -= propellant_used;
+        ship.mass -= propellant_used;
+
+        if status.is_none() && maneuver.delta_v_remaining <= DELTA_V_TOLERANCE {
+          maneuver.delta_v_remaining = 0.0;
+          status = Some(ManeuverStatus::Completed);
+        }
+      }
+    }
+
+    coast(&mut ship, integrator, gravitational_parameter, thrust_acceleration, &maneuver.burn_direction, dt);
+    ctx.db.entity().id().update(ship);
+
+    match status {
+      Some(status) => finish(ctx, maneuver, status),
+      None => {
+        ctx.db.maneuver().id().update(maneuver);
+      }
+    }
+  }
+}
+
+fn finish(ctx: &ReducerContext, mut maneuver: Maneuver, status: ManeuverStatus) {
+  log::info!("Maneuver {} for entity {} finished: {:?}", maneuver.id, maneuver.entity_id, status);
+  maneuver.status = status;
+  maneuver.finished_at = Some(ctx.timestamp);
+  ctx.db.maneuver().id().update(maneuver);
+}
+
+/// Prograde, normal and radial out axes of the ship's orbit around the node.
+/// None when the ship is at rest or moving straight toward or away from the
+/// node's centre, where there is no orbital plane.
+fn orbital_frame(ship: &Entity) -> Option<(DVec3, DVec3, DVec3)> {
+  let position = ship.relative_position;
+  let velocity = ship.relative_velocity;
+  let angular_momentum = position.cross(&velocity);
+  // Also catches a zero position or velocity, where both sides are zero
+  if angular_momentum.length() <= 1e-9 * position.length() * velocity.length() {
+    return None;
+  }
+  let prograde = velocity.normalize();
+  let normal = angular_momentum.normalize();
+  Some((prograde, normal, prograde.cross(&normal)))
+}
+
+/// Unit burn direction in the node frame, if the maneuver's frame is defined
+fn burn_direction(ship: &Entity, maneuver: &Maneuver) -> Option<DVec3> {
+  let delta_v = maneuver.delta_v;
+  let world = match maneuver.frame {
+    ManeuverFrame::World => delta_v,
+    ManeuverFrame::Orbital => {
+      let (prograde, normal, radial) = orbital_frame(ship)?;
+      prograde * delta_v.x + normal * delta_v.y + radial * delta_v.z
+    },
+  };
+  Some(world.normalize()).filter(|direction| direction.length() > 0.0)
+}
+
+/// Turn the ship toward a direction with its nav thrusters. Returns true once aligned.
+fn slew_toward(ship: &mut Entity, direction: &DVec3, moment_of_inertia: f64, dt: f64) -> bool {
+  let target_quat = DQuat::from_rotation_arc(DVec3::FORWARD, *direction);
+  let current_quat = ship.relative_rotation;
+  let rotation_diff = current_quat.angle_between(&target_quat);
+
+  if rotation_diff < ALIGNMENT_TOLERANCE {
+    ship.relative_rotation = target_quat;
+    ship.relative_rotational_velocity = DVec3 { x: 0.0, y: 0.0, z: 0.0 };
+    return true;
+  }
+
+  let max_angular_acceleration = ship.max_impulse.z / moment_of_inertia;
+
+  // Speed up, but never faster than we can stop from before reaching the target
+  let current_speed = ship.relative_rotational_velocity.length();
+  let max_safe_speed = (2.0 * max_angular_acceleration * rotation_diff).sqrt();
+  let angular_speed = (current_speed + max_angular_acceleration * dt).min(max_safe_speed);
+
+  let t = (angular_speed * dt / rotation_diff).min(1.0);
+  let axis = current_quat.axis_to(&target_quat);
+  ship.relative_rotation = current_quat.slerp(&target_quat, t);
+  ship.relative_rotational_velocity = axis * angular_speed;
+  false
+}
+
+/// Integrate the ship's motion for one step with optional thrust along the burn direction
+fn coast(
+  ship: &mut Entity,
+  integrator: Integrator,
+  gravitational_parameter: f64,
+  thrust_acceleration: f64,
+  burn_direction: &DVec3,
+  dt: f64,
+) {
+  trajectory::integrate_translation(
+    integrator,
+    *burn_direction * thrust_acceleration,
+    gravitational_parameter,
+    &mut ship.relative_position,
+    &mut ship.relative_velocity,
+    dt,
+  );
+}
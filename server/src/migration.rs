@@ -0,0 +1,192 @@
+//! Ordered data migrations, so long-running worlds survive module upgrades.
+//!
+//! `schema_version` records the last migration applied. Publishing a new
+//! module does not call `init`, so after an upgrade an admin runs the
+//! `migrate` reducer. Until every step has succeeded the world is blocked:
+//! the scheduled ticks stand still rather than run new code on old data. A
+//! failing step rolls the whole run back, so the world stays blocked at its
+//! old version and `migrate` can simply be called again once fixed.
+//!
+//! A step only rewrites rows. Column changes to existing tables, e.g. to
+//! `entity` or `node`, go through a snapshot instead: `export_world`,
+//! republish with the database cleared, then `import_world`. The document
+//! fills new columns from their serde defaults and the import runs the steps
+//! newer than the version it recorded.
+//!
+//! To add a step, append it to `MIGRATIONS` with the next version number.
+//! Never edit or reorder a step that has shipped.
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table, Timestamp};
+
+use shared::catalog;
+
+use crate::admin;
+use crate::ids;
+use crate::ship_class;
+use crate::{entity, node, EntityType, NodeName};
+
+const SCHEMA_VERSION_ID: u32 = 0;
+
+/// Schema version of the world data - a single row
+#[table(name = schema_version, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SchemaVersion {
+  #[primary_key]
+  id: u32,
+  version: u32,
+  #[serde(with = "crate::snapshot::timestamp")]
+  migrated_at: Timestamp,
+}
+
+struct Migration {
+  version: u32,
+  description: &'static str,
+  run: fn(&ReducerContext) -> Result<(), String>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+  Migration {
+    version: 1,
+    description: "Baseline: worlds from before schema versioning",
+    run: |_| Ok(()),
+  },
+  Migration {
+    version: 2,
+    description: "Turn custom entity types and node names that match a built-in variant into that variant",
+    run: promote_custom_variants,
+  },
+  Migration {
+    version: 3,
+    description: "Backfill node radius from the catalog",
+    run: backfill_node_radius,
+  },
+  Migration {
+    version: 4,
+    description: "Seed the built-in ship classes",
+    run: ship_class::seed,
+  },
+  Migration {
+    version: 5,
+    description: "Hand out row ids from counters, above the rows auto_inc numbered",
+    run: ids::reserve_existing,
+  },
+];
+
+/// Version a world is at once every migration has run
+pub fn latest() -> u32 {
+  MIGRATIONS.last().map(|migration| migration.version).unwrap_or(0)
+}
+
+/// Version the world data is at. Worlds from before versioning count as 0.
+pub fn current(ctx: &ReducerContext) -> u32 {
+  ctx.db.schema_version().id().find(&SCHEMA_VERSION_ID)
+    .map(|row| row.version)
+    .unwrap_or(0)
+}
+
+/// Whether the world is waiting for `migrate`. Checked by every scheduled
+/// reducer before it touches the world.
+pub fn is_blocked(ctx: &ReducerContext) -> bool {
+  current(ctx) < latest()
+}
+
+/// Mark a freshly created world as up to date. Called from `init`.
+pub fn init(ctx: &ReducerContext) {
+  set_version(ctx, latest());
+}
+
+/// Run every migration newer than the world, in order. Stops at the first
+/// failure; the caller's reducer then fails and nothing is kept.
+pub fn run_pending(ctx: &ReducerContext) -> Result<(), String> {
+  let from = current(ctx);
+  if from >= latest() {
+    return Ok(());
+  }
+
+  for migration in MIGRATIONS.iter().filter(|migration| migration.version > from) {
+    log::info!("Running migration {}: {}", migration.version, migration.description);
+    (migration.run)(ctx).map_err(|err| format!("Migration {} failed: {}", migration.version, err))?;
+    set_version(ctx, migration.version);
+  }
+  log::info!("Migrated world from schema version {} to {}", from, latest());
+  Ok(())
+}
+
+/// Put back the version row taken from a snapshot. A snapshot without one
+/// leaves the world at version 0, so every migration runs on it.
+pub fn restore(ctx: &ReducerContext, rows: Vec<SchemaVersion>) {
+  ctx.db.schema_version().id().delete(&SCHEMA_VERSION_ID);
+  for row in rows {
+    ctx.db.schema_version().insert(row);
+  }
+}
+
+fn set_version(ctx: &ReducerContext, version: u32) {
+  let row = SchemaVersion {
+    id: SCHEMA_VERSION_ID,
+    version,
+    migrated_at: ctx.timestamp,
+  };
+  if ctx.db.schema_version().id().find(&SCHEMA_VERSION_ID).is_some() {
+    ctx.db.schema_version().id().update(row);
+  } else {
+    ctx.db.schema_version().insert(row);
+  }
+}
+
+fn promote_custom_variants(ctx: &ReducerContext) -> Result<(), String> {
+  let entities: Vec<_> = ctx.db.entity().iter().collect();
+  for mut entity in entities {
+    let mut changed = false;
+    if let EntityType::Custom(name) = &entity.entity_type {
+      // Names that aren't a built-in kind stay custom
+      if let Ok(parsed) = name.parse::<EntityType>() {
+        entity.entity_type = parsed;
+        changed = true;
+      }
+    }
+    if let NodeName::Custom(name) = &entity.node {
+      let parsed: NodeName = name.parse()?;
+      if !matches!(parsed, NodeName::Custom(_)) {
+        entity.node = parsed;
+        changed = true;
+      }
+    }
+    if changed {
+      log::info!("Promoted {} to {} in {}", entity.designation, entity.entity_type, entity.node);
+      ctx.db.entity().id().update(entity);
+    }
+  }
+
+  let nodes: Vec<_> = ctx.db.node().iter().collect();
+  for mut node in nodes {
+    if let NodeName::Custom(name) = &node.name {
+      let parsed: NodeName = name.parse()?;
+      if !matches!(parsed, NodeName::Custom(_)) {
+        log::info!("Promoted node {} to a built-in name", parsed);
+        node.name = parsed;
+        ctx.db.node().id().update(node);
+      }
+    }
+  }
+  Ok(())
+}
+
+fn backfill_node_radius(ctx: &ReducerContext) -> Result<(), String> {
+  let nodes: Vec<_> = ctx.db.node().iter().filter(|node| node.radius == 0.0).collect();
+  for mut node in nodes {
+    if let Some(body) = catalog::find(&node.name) {
+      node.radius = body.radius;
+      ctx.db.node().id().update(node);
+    }
+  }
+  Ok(())
+}
+
+/// Run pending migrations, unblocking the world once they all succeed
+#[reducer]
+pub fn migrate(ctx: &ReducerContext) -> Result<(), String> {
+  admin::require(ctx)?;
+  run_pending(ctx)
+}
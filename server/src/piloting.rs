@@ -0,0 +1,222 @@
+//! Manual flight. A player takes control of a ship and streams numbered
+//! thrust inputs; every tick applies the oldest one and records it as
+//! acknowledged, together with the resulting state, so the pilot's client
+//! can rewind to it and replay what it has sent since. The ship only moves
+//! when an input is applied, so every server step is one the client made.
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, Identity, ReducerContext, Table};
+
+use shared::piloting::{self, ShipParameters};
+
+use crate::admin;
+use crate::flight::{self, EntityState, FlightState};
+use crate::formation;
+use crate::ids;
+use crate::maneuver;
+use crate::settings;
+use crate::trajectory::Integrator;
+use crate::{entity, DQuat, DVec3, EntityType};
+
+/// Inputs queued beyond this are dropped, oldest first, so a client that
+/// fell behind catches up instead of flying seconds in the past
+const MAX_QUEUED_INPUTS: usize = 20;
+
+/// A ship under manual control, with the state after the last applied input
+#[table(name = pilot, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Pilot {
+  #[primary_key]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  #[unique]
+  #[serde(with = "crate::snapshot::identity")]
+  identity: Identity,
+  acknowledged: u32, // Sequence of the last input applied
+  thrust: DVec3, // Body-frame thrust of the last applied input
+  position: DVec3,
+  velocity: DVec3,
+  rotation: DQuat,
+  rotational_velocity: DVec3,
+  // What the step depended on, so the client replays with the same values
+  integrator: Integrator,
+  gravitational_parameter: f64,
+}
+
+/// Inputs received but not applied yet
+#[table(name = pilot_input)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PilotInput {
+  #[primary_key]
+  pub(crate) id: u64,
+  #[index(btree)]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  sequence: u32,
+  thrust: DVec3,
+}
+
+/// Whether a player is flying the entity by hand
+pub fn is_piloted(ctx: &ReducerContext, entity_id: u64) -> bool {
+  ctx.db.pilot().entity_id().find(&entity_id).is_some()
+}
+
+/// Whether the caller is the player flying the entity
+pub fn is_pilot(ctx: &ReducerContext, entity_id: u64) -> bool {
+  ctx.db.pilot().entity_id().find(&entity_id).is_some_and(|pilot| pilot.identity == ctx.sender)
+}
+
+/// Fail unless the caller flies the entity or is an admin
+pub fn require_pilot_or_admin(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+  if is_pilot(ctx, entity_id) || admin::require(ctx).is_ok() {
+    Ok(())
+  } else {
+    Err(format!("Only the pilot of entity {} or an admin can do that", entity_id))
+  }
+}
+
+/// Apply one queued input to every piloted ship. Called once per tick. A
+/// ship with nothing queued waits for its pilot instead of flying a step
+/// the client doesn't know about.
+pub fn fly_pilots(ctx: &ReducerContext) {
+  let integrator = settings::integrator(ctx);
+  let pilots: Vec<Pilot> = ctx.db.pilot().iter().collect();
+
+  for mut pilot in pilots {
+    let Some(mut ship) = ctx.db.entity().id().find(&pilot.entity_id) else {
+      ctx.db.pilot().entity_id().delete(&pilot.entity_id);
+      continue;
+    };
+
+    let mut queued: Vec<PilotInput> = ctx.db.pilot_input().entity_id().filter(&pilot.entity_id).collect();
+    queued.sort_by_key(|input| input.sequence);
+    let stale = queued.len().saturating_sub(MAX_QUEUED_INPUTS);
+    for input in queued.drain(..stale) {
+      ctx.db.pilot_input().id().delete(&input.id);
+      pilot.acknowledged = input.sequence;
+    }
+    let Some(input) = queued.first() else {
+      continue;
+    };
+    ctx.db.pilot_input().id().delete(&input.id);
+    pilot.acknowledged = input.sequence;
+    pilot.thrust = input.thrust;
+
+    let parameters = ShipParameters {
+      integrator,
+      gravitational_parameter: flight::gravitational_parameter(ctx, &ship.node),
+      mass: ship.mass,
+      max_thrust: ship.max_impulse,
+    };
+    let mut state = FlightState::from_entity(&ship);
+    piloting::step(&mut state, pilot.thrust, &parameters);
+    state.write_to(&mut ship);
+
+    pilot.position = ship.relative_position;
+    pilot.velocity = ship.relative_velocity;
+    pilot.rotation = ship.relative_rotation;
+    pilot.rotational_velocity = ship.relative_rotational_velocity;
+    pilot.integrator = parameters.integrator;
+    pilot.gravitational_parameter = parameters.gravitational_parameter;
+
+    ctx.db.entity().id().update(ship);
+    ctx.db.pilot().entity_id().update(pilot);
+  }
+}
+
+fn clear_inputs(ctx: &ReducerContext, entity_id: u64) {
+  let ids: Vec<u64> = ctx.db.pilot_input().entity_id().filter(&entity_id).map(|input| input.id).collect();
+  for id in ids {
+    ctx.db.pilot_input().id().delete(&id);
+  }
+}
+
+/// Fly a ship by hand. A player pilots one ship at a time.
+#[reducer]
+pub fn take_control(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+  let ship = ctx.db.entity().id().find(&entity_id).ok_or_else(|| format!("Entity {} not found", entity_id))?;
+  if ship.entity_type != EntityType::Ship {
+    return Err(format!("Entity {} is not a ship", entity_id));
+  }
+  if formation::is_following(ctx, entity_id) {
+    return Err(format!("Entity {} is flying in formation", entity_id));
+  }
+  // A planned burn would fly the ship from under the pilot once it comes due
+  if maneuver::is_pending(ctx, entity_id) {
+    return Err(format!("Entity {} has a maneuver planned or in progress", entity_id));
+  }
+  if let Some(pilot) = ctx.db.pilot().entity_id().find(&entity_id) {
+    if pilot.identity != ctx.sender {
+      return Err(format!("Entity {} is already piloted by someone else", entity_id));
+    }
+    // Already flying it - keep the sequence the client is counting from
+    return Ok(());
+  }
+
+  // Let go of whatever this player flew before
+  if let Some(previous) = ctx.db.pilot().identity().find(&ctx.sender) {
+    clear_inputs(ctx, previous.entity_id);
+    ctx.db.pilot().entity_id().delete(&previous.entity_id);
+  }
+  clear_inputs(ctx, entity_id);
+
+  ctx.db.pilot().insert(Pilot {
+    entity_id,
+    identity: ctx.sender,
+    acknowledged: 0,
+    thrust: DVec3::ZERO,
+    position: ship.relative_position,
+    velocity: ship.relative_velocity,
+    rotation: ship.relative_rotation,
+    rotational_velocity: ship.relative_rotational_velocity,
+    integrator: settings::integrator(ctx),
+    gravitational_parameter: flight::gravitational_parameter(ctx, &ship.node),
+  });
+  log::info!("{} took control of {}", ctx.sender, ship.designation);
+  Ok(())
+}
+
+#[reducer]
+pub fn release_control(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+  match ctx.db.pilot().entity_id().find(&entity_id) {
+    Some(pilot) if pilot.identity == ctx.sender => {
+      clear_inputs(ctx, entity_id);
+      ctx.db.pilot().entity_id().delete(&entity_id);
+      log::info!("{} released control of entity {}", ctx.sender, entity_id);
+      Ok(())
+    },
+    _ => Err(format!("You are not piloting entity {}", entity_id)),
+  }
+}
+
+/// A pilot who drops off lets go of the ship, queued inputs and all
+#[reducer(client_disconnected)]
+pub fn release_on_disconnect(ctx: &ReducerContext) {
+  if let Some(pilot) = ctx.db.pilot().identity().find(&ctx.sender) {
+    clear_inputs(ctx, pilot.entity_id);
+    ctx.db.pilot().entity_id().delete(&pilot.entity_id);
+    log::info!("{} disconnected, released entity {}", ctx.sender, pilot.entity_id);
+  }
+}
+
+/// Queue the thrust for one solver step. Sequences count up from 1.
+#[reducer]
+pub fn pilot_input(ctx: &ReducerContext, entity_id: u64, sequence: u32, thrust: DVec3) -> Result<(), String> {
+  let pilot = ctx.db.pilot().entity_id().find(&entity_id)
+    .filter(|pilot| pilot.identity == ctx.sender)
+    .ok_or_else(|| format!("You are not piloting entity {}", entity_id))?;
+  // The clamp in the solver lets NaN through, and it would stick to the ship
+  if !thrust.is_finite() {
+    return Err("Thrust must be finite".to_string());
+  }
+  if sequence <= pilot.acknowledged {
+    // Arrived after a newer input was applied - nothing left to do with it
+    return Ok(());
+  }
+
+  ctx.db.pilot_input().insert(PilotInput {
+    id: ids::next(ctx, "pilot_input"),
+    entity_id,
+    sequence,
+    thrust,
+  });
+  Ok(())
+}
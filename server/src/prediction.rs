@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table, Timestamp};
+
+use shared::autopilot::{self, AutopilotState};
+
+use crate::flight::{self, Control, EntityState, FlightState};
+use crate::settings;
+use crate::ship_class;
+use crate::trajectory;
+use crate::{entity, DVec3};
+
+const MAX_HORIZON: f64 = 600.0; // seconds
+const MAX_SAMPLES: u32 = 1000;
+const REFRESH_INTERVAL_MICROS: i64 = 1_000_000; // Re-predict once per second
+
+/// Prediction settings for one entity, kept up to date by the tick
+#[table(name = path_prediction, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PathPrediction {
+  #[primary_key]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  horizon: f64, // seconds to look ahead
+  resolution: f64, // seconds between samples
+  #[serde(with = "crate::snapshot::timestamp")]
+  last_refreshed: Timestamp,
+}
+
+#[table(name = predicted_path, public)]
+pub struct PredictedPath {
+  #[primary_key]
+  #[auto_inc]
+  pub(crate) id: u64,
+  #[index(btree)]
+  entity_id: u64, // Foreign key to Entity table
+  sample_index: u32, // 0 = first sample after now
+  time_offset: f64, // seconds from the moment of prediction
+  position: DVec3,
+  velocity: DVec3,
+}
+
+/// Start (or reconfigure) path prediction for an entity
+#[reducer]
+pub fn request_path_prediction(
+  ctx: &ReducerContext,
+  entity_id: u64,
+  horizon: f64,
+  resolution: f64,
+) -> Result<(), String> {
+  if ctx.db.entity().id().find(&entity_id).is_none() {
+    return Err(format!("Entity {} not found", entity_id));
+  }
+  if !(horizon > 0.0 && horizon <= MAX_HORIZON) {
+    return Err(format!("Horizon must be between 0 and {} seconds", MAX_HORIZON));
+  }
+  if !(resolution >= trajectory::DELTA_TIME && resolution <= horizon) {
+    return Err(format!("Resolution must be between {} seconds and the horizon", trajectory::DELTA_TIME));
+  }
+  if horizon / resolution > MAX_SAMPLES as f64 {
+    return Err(format!("At most {} samples per prediction", MAX_SAMPLES));
+  }
+
+  let prediction = PathPrediction {
+    entity_id,
+    horizon,
+    resolution,
+    last_refreshed: ctx.timestamp,
+  };
+  if ctx.db.path_prediction().entity_id().find(&entity_id).is_some() {
+    ctx.db.path_prediction().entity_id().update(prediction);
+  } else {
+    ctx.db.path_prediction().insert(prediction);
+  }
+
+  predict_path(ctx, entity_id, horizon, resolution);
+  Ok(())
+}
+
+/// Stop predicting an entity's path and clear its samples
+#[reducer]
+pub fn cancel_path_prediction(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+  if !ctx.db.path_prediction().entity_id().delete(&entity_id) {
+    return Err(format!("No path prediction for entity {}", entity_id));
+  }
+  clear_path(ctx, entity_id);
+  Ok(())
+}
+
+/// Re-run predictions that are due. Called once per tick.
+pub fn refresh_predictions(ctx: &ReducerContext) {
+  let now = ctx.timestamp.to_micros_since_unix_epoch();
+  let due: Vec<PathPrediction> = ctx.db.path_prediction().iter()
+    .filter(|prediction| now - prediction.last_refreshed.to_micros_since_unix_epoch() >= REFRESH_INTERVAL_MICROS)
+    .collect();
+
+  for mut prediction in due {
+    if ctx.db.entity().id().find(&prediction.entity_id).is_none() {
+      // Entity is gone - drop the prediction with it
+      ctx.db.path_prediction().entity_id().delete(&prediction.entity_id);
+      clear_path(ctx, prediction.entity_id);
+      continue;
+    }
+
+    predict_path(ctx, prediction.entity_id, prediction.horizon, prediction.resolution);
+    prediction.last_refreshed = ctx.timestamp;
+    ctx.db.path_prediction().entity_id().update(prediction);
+  }
+}
+
+fn clear_path(ctx: &ReducerContext, entity_id: u64) {
+  let samples: Vec<PredictedPath> = ctx.db.predicted_path().entity_id().filter(&entity_id).collect();
+  for sample in samples {
+    ctx.db.predicted_path().id().delete(&sample.id);
+  }
+}
+
+/// Propagate an entity forward the way the tick moves it and store sampled points
+fn predict_path(ctx: &ReducerContext, entity_id: u64, horizon: f64, resolution: f64) {
+  let ship = match ctx.db.entity().id().find(&entity_id) {
+    Some(ship) => ship,
+    None => return,
+  };
+
+  let gravitational_parameter = flight::gravitational_parameter(ctx, &ship.node);
+  let integrator = settings::integrator(ctx);
+
+  let steps = (horizon / trajectory::DELTA_TIME).round() as u32;
+  let steps_per_sample = ((resolution / trajectory::DELTA_TIME).round() as u32).max(1);
+  let mut samples = Vec::new();
+  let mut record = |step: u32, position: DVec3, velocity: DVec3| {
+    if step % steps_per_sample == 0 {
+      samples.push((step as f64 * trajectory::DELTA_TIME, position, velocity));
+    }
+  };
+
+  match flight::current_control(ctx, &ship) {
+    Control::Formation { flight_mode, mut leader } => {
+      let mut state = FlightState::from_entity(&ship);
+      for step in 1..=steps {
+        state.step(flight_mode, leader, integrator, gravitational_parameter, ship.mass, ship.max_impulse);
+        // The leader isn't re-solved here - assume it coasts at its current velocity
+        if let Some(leader) = leader.as_mut() {
+          leader.position += leader.velocity * trajectory::DELTA_TIME;
+        }
+        record(step, state.position, state.velocity);
+      }
+    },
+    Control::Waypoints(targets) => {
+      let mut state = AutopilotState {
+        position: ship.relative_position,
+        velocity: ship.relative_velocity,
+        rotation: ship.relative_rotation,
+        rotational_velocity: ship.relative_rotational_velocity,
+      };
+      let moment_of_inertia = ship_class::moment_of_inertia(ctx, &ship);
+      let mut targets = targets.into_iter().peekable();
+      for step in 1..=steps {
+        // Past the last waypoint the tick makes up a new one - assume it stays put
+        if let Some(&target) = targets.peek() {
+          if autopilot::step(&mut state, target, ship.mass, moment_of_inertia, ship.max_impulse) {
+            targets.next();
+          }
+        }
+        record(step, state.position, state.velocity);
+      }
+    },
+    Control::Coast => {
+      let (mut position, mut velocity) = (ship.relative_position, ship.relative_velocity);
+      for step in 1..=steps {
+        trajectory::integrate_translation(
+          integrator,
+          DVec3::ZERO,
+          gravitational_parameter,
+          &mut position,
+          &mut velocity,
+          trajectory::DELTA_TIME,
+        );
+        record(step, position, velocity);
+      }
+    },
+  }
+
+  store_path(ctx, entity_id, &samples);
+  log::info!("Predicted {} samples over {:.1}s for entity {}", samples.len(), horizon, entity_id);
+}
+
+/// Write samples of (time offset, position, velocity) over the stored path,
+/// touching only the rows that changed so subscribers aren't sent the rest
+fn store_path(ctx: &ReducerContext, entity_id: u64, samples: &[(f64, DVec3, DVec3)]) {
+  let mut stored: HashMap<u32, PredictedPath> = ctx.db.predicted_path().entity_id().filter(&entity_id)
+    .map(|row| (row.sample_index, row))
+    .collect();
+
+  for (sample_index, &(time_offset, position, velocity)) in samples.iter().enumerate() {
+    let sample_index = sample_index as u32;
+    match stored.remove(&sample_index) {
+      Some(row) if row.time_offset == time_offset && row.position == position && row.velocity == velocity => {},
+      Some(row) => {
+        ctx.db.predicted_path().id().update(PredictedPath { time_offset, position, velocity, ..row });
+      },
+      None => {
+        ctx.db.predicted_path().insert(PredictedPath {
+          id: 0,
+          entity_id,
+          sample_index,
+          time_offset,
+          position,
+          velocity,
+        });
+      },
+    }
+  }
+
+  // Samples past the end of a shorter prediction
+  for row in stored.into_values() {
+    ctx.db.predicted_path().id().delete(&row.id);
+  }
+}
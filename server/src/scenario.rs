@@ -0,0 +1,277 @@
+//! Worlds described as RON files under `server/scenarios/`, embedded in the module.
+//!
+//! A scenario lists the nodes, entities and waypoints to start with and the
+//! simulation settings. Loading one clears the previous world first.
+
+use serde::Deserialize;
+use spacetimedb::{reducer, ReducerContext, Table};
+
+use crate::admin;
+use crate::area_of_interest::entity_cell;
+use crate::formation::{formation, formation_member};
+use crate::ids;
+use crate::maneuver::maneuver;
+use crate::piloting::{pilot, pilot_input};
+use crate::prediction::{path_prediction, predicted_path};
+use crate::settings;
+use crate::ship_class;
+use crate::solar_system;
+use crate::transfer::transfer_plan;
+use crate::trajectory::Integrator;
+use crate::{animation_counter, entity, find_node, node, waypoint, DQuat, DVec3, Entity, EntityType, Node, NodeName, Waypoint};
+
+/// Scenario `init` starts the world with
+pub const DEFAULT_SCENARIO: &str = "default";
+
+/// Every scenario shipped with the module, by name
+const SCENARIOS: &[(&str, &str)] = &[
+  ("default", include_str!("../scenarios/default.ron")),
+  ("empty", include_str!("../scenarios/empty.ron")),
+  ("formation", include_str!("../scenarios/formation.ron")),
+  ("earth_orbit", include_str!("../scenarios/earth_orbit.ron")),
+];
+
+#[derive(Deserialize)]
+pub struct Scenario {
+  #[serde(default)]
+  pub description: String,
+  /// Seed the catalogued Sun, planets and moons before the scenario's own nodes
+  #[serde(default)]
+  pub solar_system: bool,
+  #[serde(default)]
+  pub settings: ScenarioSettings,
+  #[serde(default)]
+  pub nodes: Vec<ScenarioNode>,
+  #[serde(default)]
+  pub entities: Vec<ScenarioEntity>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ScenarioSettings {
+  #[serde(default)]
+  pub integrator: Integrator,
+}
+
+#[derive(Deserialize)]
+pub struct ScenarioNode {
+  pub name: NodeName,
+  #[serde(default)]
+  pub parent: Option<NodeName>,
+  #[serde(default = "zero")]
+  pub position: DVec3,
+  #[serde(default = "zero")]
+  pub velocity: DVec3,
+  #[serde(default)]
+  pub mass: f64,
+  #[serde(default)]
+  pub radius: f64,
+}
+
+#[derive(Deserialize)]
+pub struct ScenarioEntity {
+  pub designation: String,
+  pub entity_type: EntityType,
+  pub node: NodeName,
+  pub position: DVec3,
+  #[serde(default = "zero")]
+  pub velocity: DVec3,
+  #[serde(default = "identity")]
+  pub rotation: DQuat,
+  /// Ship class to take mass, thrust and engine from. The fields below override it.
+  #[serde(default)]
+  pub class: Option<String>,
+  #[serde(default)]
+  pub mass: Option<f64>,
+  #[serde(default)]
+  pub max_impulse: Option<DVec3>, // main thrust (x), retro thrust (y), nav thrust (z)
+  #[serde(default)]
+  pub propellant: Option<f64>,
+  #[serde(default)]
+  pub specific_impulse: Option<f64>,
+  /// Visited in order, in the entity's node frame
+  #[serde(default)]
+  pub waypoints: Vec<DVec3>,
+}
+
+fn zero() -> DVec3 {
+  DVec3::ZERO
+}
+
+fn identity() -> DQuat {
+  DQuat::IDENTITY
+}
+
+/// Parse an embedded scenario by name
+pub fn find(name: &str) -> Result<Scenario, String> {
+  let (_, source) = SCENARIOS.iter()
+    .find(|(scenario, _)| *scenario == name)
+    .ok_or_else(|| format!("Unknown scenario '{}'", name))?;
+  ron::from_str(source).map_err(|err| format!("Scenario '{}' is invalid: {}", name, err))
+}
+
+/// Replace the whole world with the named scenario
+pub fn load(ctx: &ReducerContext, name: &str) -> Result<(), String> {
+  // Parse before clearing so a broken file leaves the world alone
+  let scenario = find(name)?;
+
+  clear_world(ctx);
+
+  if scenario.solar_system {
+    solar_system::seed(ctx);
+  }
+
+  for spec in &scenario.nodes {
+    let parent_id = match &spec.parent {
+      Some(parent) => Some(find_node(ctx, parent).ok_or_else(|| format!("Parent node {} not found", parent))?.id),
+      None => None,
+    };
+    ctx.db.node().insert(Node {
+      id: ids::next(ctx, "node"),
+      name: spec.name.clone(),
+      parent_id,
+      node_position: spec.position,
+      node_velocity: spec.velocity,
+      node_rotation: DQuat::IDENTITY,
+      node_rotational_velocity: DVec3::ZERO,
+      orbit_progress: 0.0,
+      mass: spec.mass,
+      radius: spec.radius,
+    });
+  }
+
+  for spec in &scenario.entities {
+    if find_node(ctx, &spec.node).is_none() {
+      return Err(format!("Entity {} is in unknown node {}", spec.designation, spec.node));
+    }
+    let entity = ctx.db.entity().insert(build_entity(ctx, spec)?);
+    insert_waypoints(ctx, entity.id, &spec.waypoints);
+  }
+
+  settings::apply_scenario(ctx, name, scenario.settings.integrator);
+  log::info!(
+    "Loaded scenario '{}': {} nodes, {} entities",
+    name,
+    ctx.db.node().count(),
+    scenario.entities.len()
+  );
+  Ok(())
+}
+
+/// Put every entity the current scenario defines back at its starting state
+pub fn reset_entities(ctx: &ReducerContext) -> Result<(), String> {
+  let name = settings::scenario(ctx);
+  let scenario = find(&name)?;
+
+  for spec in &scenario.entities {
+    let Some(mut entity) = ctx.db.entity().designation().find(&spec.designation) else {
+      log::info!("{} not found, skipping", spec.designation);
+      continue;
+    };
+    entity.relative_position = spec.position;
+    entity.relative_velocity = spec.velocity;
+    entity.relative_rotation = spec.rotation;
+    entity.relative_rotational_velocity = DVec3::ZERO;
+    let entity_id = entity.id;
+    ctx.db.entity().id().update(entity);
+
+    let waypoint_ids: Vec<u64> = ctx.db.waypoint().entity_id().filter(&entity_id).map(|waypoint| waypoint.id).collect();
+    for id in waypoint_ids {
+      ctx.db.waypoint().id().delete(&id);
+    }
+    insert_waypoints(ctx, entity_id, &spec.waypoints);
+    log::info!("Reset {} to its start in scenario '{}'", spec.designation, name);
+  }
+  Ok(())
+}
+
+/// The entity a spec describes, from its class if it names one
+fn build_entity(ctx: &ReducerContext, spec: &ScenarioEntity) -> Result<Entity, String> {
+  let mut entity = match &spec.class {
+    Some(class) => ship_class::find(ctx, class)?.build(ids::next(ctx, "entity"), spec.designation.clone(), spec.node.clone(), spec.position),
+    None => Entity {
+      id: ids::next(ctx, "entity"),
+      node: spec.node.clone(),
+      designation: spec.designation.clone(),
+      relative_position: spec.position,
+      relative_velocity: DVec3::ZERO,
+      relative_rotation: DQuat::IDENTITY,
+      relative_rotational_velocity: DVec3::ZERO,
+      mass: spec.mass.ok_or_else(|| format!("Entity {} needs a class or a mass", spec.designation))?,
+      max_impulse: spec.max_impulse.unwrap_or(DVec3::ZERO),
+      propellant: 0.0,
+      specific_impulse: 0.0,
+      entity_type: spec.entity_type.clone(),
+      ship_class_id: None,
+    },
+  };
+
+  entity.entity_type = spec.entity_type.clone();
+  entity.relative_velocity = spec.velocity;
+  entity.relative_rotation = spec.rotation;
+  if let Some(mass) = spec.mass {
+    entity.mass = mass;
+  }
+  if let Some(max_impulse) = spec.max_impulse {
+    entity.max_impulse = max_impulse;
+  }
+  if let Some(propellant) = spec.propellant {
+    entity.propellant = propellant;
+  }
+  if let Some(specific_impulse) = spec.specific_impulse {
+    entity.specific_impulse = specific_impulse;
+  }
+  Ok(entity)
+}
+
+fn insert_waypoints(ctx: &ReducerContext, entity_id: u64, waypoints: &[DVec3]) {
+  for (order_index, target_position) in waypoints.iter().enumerate() {
+    ctx.db.waypoint().insert(Waypoint {
+      id: ids::next(ctx, "waypoint"),
+      entity_id,
+      target_position: *target_position,
+      order_index: order_index as u32,
+    });
+  }
+}
+
+/// Delete every row that describes the world. Schedules, settings and admins stay.
+pub fn clear_world(ctx: &ReducerContext) {
+  macro_rules! clear {
+    ($table:ident) => {
+      let ids: Vec<_> = ctx.db.$table().iter().map(|row| row.id).collect();
+      for id in ids {
+        ctx.db.$table().id().delete(&id);
+      }
+    };
+  }
+
+  clear!(pilot_input);
+  clear!(transfer_plan);
+  clear!(maneuver);
+  clear!(predicted_path);
+  clear!(formation_member);
+  clear!(formation);
+  clear!(waypoint);
+  clear!(entity);
+  clear!(node);
+  clear!(animation_counter);
+
+  let ids: Vec<u64> = ctx.db.path_prediction().iter().map(|row| row.entity_id).collect();
+  for id in ids {
+    ctx.db.path_prediction().entity_id().delete(&id);
+  }
+  let ids: Vec<u64> = ctx.db.pilot().iter().map(|row| row.entity_id).collect();
+  for id in ids {
+    ctx.db.pilot().entity_id().delete(&id);
+  }
+  let ids: Vec<u64> = ctx.db.entity_cell().iter().map(|row| row.entity_id).collect();
+  for id in ids {
+    ctx.db.entity_cell().entity_id().delete(&id);
+  }
+}
+
+#[reducer]
+pub fn load_scenario(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  load(ctx, &name)
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table};
+
+use crate::admin;
+use crate::trajectory::Integrator;
+
+const SETTINGS_ID: u32 = 0;
+
+/// Simulation settings for the whole world - a single row
+#[table(name = world_settings, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WorldSettings {
+  #[primary_key]
+  id: u32,
+  integrator: Integrator,
+  scenario: String, // Name of the scenario the world was last loaded from
+}
+
+/// Insert the default settings row. Called from `init`.
+pub fn init(ctx: &ReducerContext) {
+  ctx.db.world_settings().insert(WorldSettings {
+    id: SETTINGS_ID,
+    integrator: Integrator::default(),
+    scenario: String::new(),
+  });
+}
+
+/// Integrator every physics step in this world uses
+pub fn integrator(ctx: &ReducerContext) -> Integrator {
+  ctx.db.world_settings().id().find(&SETTINGS_ID)
+    .map(|settings| settings.integrator)
+    .unwrap_or_default()
+}
+
+/// Scenario the world was last loaded from
+pub fn scenario(ctx: &ReducerContext) -> String {
+  ctx.db.world_settings().id().find(&SETTINGS_ID)
+    .map(|settings| settings.scenario)
+    .unwrap_or_default()
+}
+
+/// Change the settings row, creating it if it is missing
+fn update(ctx: &ReducerContext, change: impl FnOnce(&mut WorldSettings)) {
+  match ctx.db.world_settings().id().find(&SETTINGS_ID) {
+    Some(mut settings) => {
+      change(&mut settings);
+      ctx.db.world_settings().id().update(settings);
+    },
+    None => {
+      let mut settings = WorldSettings {
+        id: SETTINGS_ID,
+        integrator: Integrator::default(),
+        scenario: String::new(),
+      };
+      change(&mut settings);
+      ctx.db.world_settings().insert(settings);
+    },
+  }
+}
+
+/// Record a freshly loaded scenario and apply its settings
+pub fn apply_scenario(ctx: &ReducerContext, name: &str, integrator: Integrator) {
+  update(ctx, |settings| {
+    settings.scenario = name.to_string();
+    settings.integrator = integrator;
+  });
+}
+
+/// Put back settings rows taken from a snapshot
+pub fn restore(ctx: &ReducerContext, rows: Vec<WorldSettings>) {
+  for row in rows {
+    ctx.db.world_settings().id().delete(&row.id);
+    ctx.db.world_settings().insert(row);
+  }
+}
+
+#[reducer]
+pub fn set_integrator(ctx: &ReducerContext, integrator: Integrator) -> Result<(), String> {
+  admin::require(ctx)?;
+  update(ctx, |settings| settings.integrator = integrator);
+  log::info!("World integrator set to {:?}", integrator);
+  Ok(())
+}
@@ -0,0 +1,108 @@
+//! Ship blueprints. Every ship built from a class gets its mass, thrust and
+//! engine from here, and clients read the dimensions, model and sensor range
+//! to build the ship's components and visuals.
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table};
+
+use shared::autopilot;
+
+use crate::admin;
+use crate::ids;
+use crate::{entity, find_node, DQuat, DVec3, Entity, EntityType, NodeName};
+
+/// Classes seeded into every world, see `server/data/ship_classes.ron`
+const SHIP_CLASSES: &str = include_str!("../data/ship_classes.ron");
+
+#[table(name = ship_class, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ShipClass {
+  #[primary_key]
+  #[serde(default)]
+  pub(crate) id: u64,
+  #[unique]
+  pub(crate) name: String,
+  model: String, // Visual model id the client builds the ship from
+  mass: f64, // kg, including a full tank
+  max_impulse: DVec3, // main thrust (x), retro thrust (y), nav thrust (z)
+  propellant: f64, // kg
+  specific_impulse: f64, // seconds
+  dimensions: DVec3, // m, width (x), height (y), length (z)
+  sensor_range: f64, // m
+}
+
+impl ShipClass {
+  /// A ship of this class at rest, ready to insert
+  pub fn build(&self, id: u64, designation: String, node: NodeName, position: DVec3) -> Entity {
+    Entity {
+      id,
+      node,
+      designation,
+      relative_position: position,
+      relative_velocity: DVec3::ZERO,
+      relative_rotation: DQuat::IDENTITY,
+      relative_rotational_velocity: DVec3::ZERO,
+      mass: self.mass,
+      max_impulse: self.max_impulse,
+      propellant: self.propellant,
+      specific_impulse: self.specific_impulse,
+      entity_type: EntityType::Ship,
+      ship_class_id: Some(self.id),
+    }
+  }
+}
+
+/// Insert every built-in class the world doesn't have yet
+pub fn seed(ctx: &ReducerContext) -> Result<(), String> {
+  let classes: Vec<ShipClass> = ron::from_str(SHIP_CLASSES).map_err(|err| format!("Ship classes are invalid: {}", err))?;
+  for class in classes {
+    if ctx.db.ship_class().name().find(&class.name).is_none() {
+      log::info!("Added ship class {}", class.name);
+      ctx.db.ship_class().insert(ShipClass { id: ids::next(ctx, "ship_class"), ..class });
+    }
+  }
+  Ok(())
+}
+
+/// Moment of inertia a ship turns with, from its class's hull or the default one
+pub fn moment_of_inertia(ctx: &ReducerContext, ship: &Entity) -> f64 {
+  let dimensions = ship.ship_class_id
+    .and_then(|id| ctx.db.ship_class().id().find(&id))
+    .map(|class| class.dimensions)
+    .unwrap_or(autopilot::DEFAULT_SHIP_DIMENSIONS);
+  autopilot::box_moment_of_inertia(ship.mass, dimensions)
+}
+
+pub fn find(ctx: &ReducerContext, name: &str) -> Result<ShipClass, String> {
+  ctx.db.ship_class().name().find(&name.to_string())
+    .ok_or_else(|| format!("Unknown ship class '{}'", name))
+}
+
+/// Build a ship of the given class at rest in a node
+#[reducer]
+pub fn spawn_ship(
+  ctx: &ReducerContext,
+  class: String,
+  designation: String,
+  node: NodeName,
+  position: DVec3,
+) -> Result<(), String> {
+  admin::require(ctx)?;
+  if designation.trim().is_empty() {
+    return Err("Designation cannot be empty".to_string());
+  }
+  if ctx.db.entity().designation().find(&designation).is_some() {
+    return Err(format!("Designation {} is already taken", designation));
+  }
+  if find_node(ctx, &node).is_none() {
+    return Err(format!("Node {} not found", node));
+  }
+  if !position.is_finite() {
+    return Err("Position must be finite".to_string());
+  }
+
+  let class = find(ctx, &class)?;
+  let ship = ctx.db.entity().insert(class.build(ids::next(ctx, "entity"), designation, node, position));
+  log::info!("Spawned {} {} (ID: {}) in {}", class.name, ship.designation, ship.id, ship.node);
+  Ok(())
+}
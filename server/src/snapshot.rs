@@ -0,0 +1,252 @@
+//! Whole-world snapshots for backups and bug reports.
+//!
+//! `export_world` serialises every game table into one versioned RON
+//! document and stores it in `world_snapshot`. `import_world` and
+//! `restore_world` replace the world with a document, keeping every id so
+//! cross references stay valid. A new game table has to be added to
+//! `WorldSnapshot`, `capture` and `restore` to be covered, and to
+//! `ids::reserve_existing` if it numbers its rows.
+
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, Table, Timestamp};
+
+use crate::admin;
+use crate::ids;
+use crate::area_of_interest::{entity_cell, EntityCell};
+use crate::formation::{formation, formation_member, Formation, FormationMember};
+use crate::maneuver::{maneuver, Maneuver};
+use crate::migration::{self, schema_version, SchemaVersion};
+use crate::piloting::{pilot, pilot_input, Pilot, PilotInput};
+use crate::prediction::{path_prediction, PathPrediction};
+use crate::scenario;
+use crate::settings::{self, world_settings, WorldSettings};
+use crate::ship_class::{self, ship_class, ShipClass};
+use crate::transfer::{transfer_plan, TransferPlan};
+use crate::{animation_counter, entity, node, waypoint, AnimationCounter, Entity, Node, Waypoint};
+
+/// Bump when the document layout changes
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[table(name = world_snapshot)]
+pub struct WorldSnapshotRow {
+  #[primary_key]
+  #[auto_inc]
+  id: u64,
+  #[unique]
+  name: String,
+  taken_at: Timestamp,
+  version: u32,
+  document: String, // RON, see `WorldSnapshot`
+}
+
+/// Every game table, row for row. Predicted paths are left out - they are
+/// derived from `path_prediction` and regenerated within a second.
+#[derive(Serialize, Deserialize)]
+struct WorldSnapshot {
+  version: u32,
+  world_settings: Vec<WorldSettings>,
+  #[serde(default)]
+  ship_classes: Vec<ShipClass>,
+  nodes: Vec<Node>,
+  entities: Vec<Entity>,
+  waypoints: Vec<Waypoint>,
+  formations: Vec<Formation>,
+  formation_members: Vec<FormationMember>,
+  maneuvers: Vec<Maneuver>,
+  path_predictions: Vec<PathPrediction>,
+  transfer_plans: Vec<TransferPlan>,
+  animation_counters: Vec<AnimationCounter>,
+  #[serde(default)]
+  pilots: Vec<Pilot>,
+  #[serde(default)]
+  pilot_inputs: Vec<PilotInput>,
+  #[serde(default)]
+  entity_cells: Vec<EntityCell>,
+  #[serde(default)]
+  schema_version: Vec<SchemaVersion>,
+}
+
+fn capture(ctx: &ReducerContext) -> WorldSnapshot {
+  WorldSnapshot {
+    version: SNAPSHOT_VERSION,
+    world_settings: ctx.db.world_settings().iter().collect(),
+    ship_classes: ctx.db.ship_class().iter().collect(),
+    nodes: ctx.db.node().iter().collect(),
+    entities: ctx.db.entity().iter().collect(),
+    waypoints: ctx.db.waypoint().iter().collect(),
+    formations: ctx.db.formation().iter().collect(),
+    formation_members: ctx.db.formation_member().iter().collect(),
+    maneuvers: ctx.db.maneuver().iter().collect(),
+    path_predictions: ctx.db.path_prediction().iter().collect(),
+    transfer_plans: ctx.db.transfer_plan().iter().collect(),
+    animation_counters: ctx.db.animation_counter().iter().collect(),
+    pilots: ctx.db.pilot().iter().collect(),
+    pilot_inputs: ctx.db.pilot_input().iter().collect(),
+    entity_cells: ctx.db.entity_cell().iter().collect(),
+    schema_version: ctx.db.schema_version().iter().collect(),
+  }
+}
+
+fn restore(ctx: &ReducerContext, snapshot: WorldSnapshot) {
+  scenario::clear_world(ctx);
+
+  // Entities refer to classes by id, so the classes are replaced too
+  let class_ids: Vec<u64> = ctx.db.ship_class().iter().map(|row| row.id).collect();
+  for id in class_ids {
+    ctx.db.ship_class().id().delete(&id);
+  }
+
+  // Every row keeps its id, so the references between them stay as they were
+  for row in snapshot.ship_classes {
+    ctx.db.ship_class().insert(row);
+  }
+  for row in snapshot.nodes {
+    ctx.db.node().insert(row);
+  }
+  for row in snapshot.entities {
+    ctx.db.entity().insert(row);
+  }
+  for row in snapshot.waypoints {
+    ctx.db.waypoint().insert(row);
+  }
+  for row in snapshot.formations {
+    ctx.db.formation().insert(row);
+  }
+  for row in snapshot.formation_members {
+    ctx.db.formation_member().insert(row);
+  }
+  for row in snapshot.maneuvers {
+    ctx.db.maneuver().insert(row);
+  }
+  for row in snapshot.path_predictions {
+    ctx.db.path_prediction().insert(row);
+  }
+  for row in snapshot.transfer_plans {
+    ctx.db.transfer_plan().insert(row);
+  }
+  for row in snapshot.animation_counters {
+    ctx.db.animation_counter().insert(row);
+  }
+  for row in snapshot.pilots {
+    ctx.db.pilot().insert(row);
+  }
+  for row in snapshot.pilot_inputs {
+    ctx.db.pilot_input().insert(row);
+  }
+  for row in snapshot.entity_cells {
+    ctx.db.entity_cell().insert(row);
+  }
+  settings::restore(ctx, snapshot.world_settings);
+  migration::restore(ctx, snapshot.schema_version);
+}
+
+/// Replace the world with a snapshot document
+fn import(ctx: &ReducerContext, document: &str) -> Result<(), String> {
+  let snapshot: WorldSnapshot = ron::from_str(document).map_err(|err| format!("Invalid snapshot: {}", err))?;
+  if snapshot.version != SNAPSHOT_VERSION {
+    return Err(format!(
+      "Snapshot version {} is not supported (expected {})",
+      snapshot.version, SNAPSHOT_VERSION
+    ));
+  }
+  restore(ctx, snapshot);
+  // New rows must not reuse an id the document brought back
+  ids::reserve_existing(ctx)?;
+  // Bring a document from an older module up to date
+  migration::run_pending(ctx)?;
+  // Documents from before ship classes carry none
+  ship_class::seed(ctx)?;
+  log::info!("Restored world snapshot: {} nodes, {} entities", ctx.db.node().count(), ctx.db.entity().count());
+  Ok(())
+}
+
+/// Serialise the world into `world_snapshot` under the given name, replacing an older one
+#[reducer]
+pub fn export_world(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  if name.trim().is_empty() {
+    return Err("Snapshot name cannot be empty".to_string());
+  }
+
+  let document = ron::ser::to_string_pretty(&capture(ctx), ron::ser::PrettyConfig::default())
+    .map_err(|err| format!("Could not serialise the world: {}", err))?;
+
+  ctx.db.world_snapshot().name().delete(&name);
+  ctx.db.world_snapshot().insert(WorldSnapshotRow {
+    id: 0,
+    name: name.clone(),
+    taken_at: ctx.timestamp,
+    version: SNAPSHOT_VERSION,
+    document,
+  });
+  log::info!("Exported world snapshot '{}'", name);
+  Ok(())
+}
+
+/// Replace the world with a document, e.g. one attached to a bug report
+#[reducer]
+pub fn import_world(ctx: &ReducerContext, document: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  import(ctx, &document)
+}
+
+/// Replace the world with a snapshot stored by `export_world`
+#[reducer]
+pub fn restore_world(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  let snapshot = ctx.db.world_snapshot().name().find(&name).ok_or_else(|| format!("No snapshot named '{}'", name))?;
+  import(ctx, &snapshot.document)
+}
+
+#[reducer]
+pub fn delete_snapshot(ctx: &ReducerContext, name: String) -> Result<(), String> {
+  admin::require(ctx)?;
+  if ctx.db.world_snapshot().name().delete(&name) {
+    Ok(())
+  } else {
+    Err(format!("No snapshot named '{}'", name))
+  }
+}
+
+/// Timestamps as microseconds since the Unix epoch, for `#[serde(with)]`
+pub mod timestamp {
+  use serde::{Deserialize, Deserializer, Serializer};
+  use spacetimedb::Timestamp;
+
+  pub fn serialize<S: Serializer>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(timestamp.to_micros_since_unix_epoch())
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+    i64::deserialize(deserializer).map(Timestamp::from_micros_since_unix_epoch)
+  }
+}
+
+/// Optional timestamps as microseconds since the Unix epoch, for `#[serde(with)]`
+pub mod optional_timestamp {
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+  use spacetimedb::Timestamp;
+
+  pub fn serialize<S: Serializer>(timestamp: &Option<Timestamp>, serializer: S) -> Result<S::Ok, S::Error> {
+    timestamp.map(|timestamp| timestamp.to_micros_since_unix_epoch()).serialize(serializer)
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Timestamp>, D::Error> {
+    Option::<i64>::deserialize(deserializer).map(|micros| micros.map(Timestamp::from_micros_since_unix_epoch))
+  }
+}
+
+/// Identities as hex strings, for `#[serde(with)]`
+pub mod identity {
+  use serde::{de::Error, Deserialize, Deserializer, Serializer};
+  use spacetimedb::Identity;
+
+  pub fn serialize<S: Serializer>(identity: &Identity, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(identity)
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Identity, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    Identity::from_hex(&hex).map_err(D::Error::custom)
+  }
+}
@@ -0,0 +1,47 @@
+//! Seeds the `node` table with the solar system from `shared::catalog`.
+
+use spacetimedb::{ReducerContext, Table};
+
+use shared::catalog;
+
+use crate::ids;
+use crate::{node, DQuat, DVec3, Node, NodeName};
+
+/// Insert every catalogued body at its J2000 state, parents first
+pub fn seed(ctx: &ReducerContext) {
+  let bodies = catalog::bodies();
+  let mut ids: Vec<(NodeName, u64)> = Vec::with_capacity(bodies.len());
+
+  for body in &bodies {
+    let parent = body.parent.as_ref().and_then(|parent| {
+      let found = bodies.iter().find(|other| other.name == *parent);
+      let id = ids.iter().find(|(name, _)| name == parent).map(|(_, id)| *id);
+      found.zip(id)
+    });
+
+    let (position, velocity, orbit_progress) = match (&body.orbit, parent) {
+      (Some(orbit), Some((parent, _))) => {
+        let (position, velocity) = orbit.state_vector(parent.gravitational_parameter());
+        let orbit_progress = orbit.mean_anomaly.rem_euclid(360.0) / 360.0;
+        (position, velocity, orbit_progress)
+      },
+      _ => (DVec3::ZERO, DVec3::ZERO, 0.0),
+    };
+
+    let node = ctx.db.node().insert(Node {
+      id: ids::next(ctx, "node"),
+      name: body.name.clone(),
+      parent_id: parent.map(|(_, id)| id),
+      node_position: position,
+      node_velocity: velocity,
+      node_rotation: DQuat::IDENTITY,
+      node_rotational_velocity: body.rotational_velocity(),
+      orbit_progress,
+      mass: body.mass,
+      radius: body.radius,
+    });
+    ids.push((body.name.clone(), node.id));
+  }
+
+  log::info!("Seeded {} catalogued bodies", ids.len());
+}
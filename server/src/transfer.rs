@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::ids;
+use crate::maneuver::{self, ManeuverFrame};
+use crate::piloting;
+use crate::trajectory;
+use crate::{entity, find_node, node, DQuat, DVec3, NodeName};
+
+/// Ships that can accelerate at least this hard fly brachistochrones by default
+const HIGH_THRUST_ACCELERATION: f64 = 0.5; // m/s²
+const DEPARTURE_MARGIN: f64 = 5.0; // seconds on top of the maneuver orientation lead
+
+#[derive(SpacetimeType, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum TransferMethod {
+  /// Two-burn transfer between circular orbits around a common body
+  Hohmann,
+  /// Accelerate toward the target, flip at midpoint, decelerate
+  Brachistochrone,
+}
+
+/// A transfer planned for a ship. Every plan adds a row, so a ship has one
+/// per `plan_transfer` call, oldest first by id.
+#[table(name = transfer_plan, public)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TransferPlan {
+  #[primary_key]
+  pub(crate) id: u64,
+  #[index(btree)]
+  pub(crate) entity_id: u64, // Foreign key to Entity table
+  destination: NodeName,
+  method: TransferMethod,
+  #[serde(with = "crate::snapshot::timestamp")]
+  departure_at: Timestamp,
+  #[serde(with = "crate::snapshot::timestamp")]
+  arrival_at: Timestamp,
+  delta_v: f64, // m/s, total over all burns
+  travel_time: f64, // seconds from departure to arrival
+  pub(crate) maneuver_ids: Vec<u64>,
+}
+
+/// The body both ends of a transfer orbit, with each end's state in its frame
+struct TransferGeometry {
+  central_body: NodeName,
+  gravitational_parameter: f64,
+  departure_position: DVec3,
+  departure_velocity: DVec3,
+  target_position: DVec3,
+  target_velocity: DVec3,
+}
+
+#[reducer]
+pub fn plan_transfer(
+  ctx: &ReducerContext,
+  entity_id: u64,
+  destination: NodeName,
+  method: Option<TransferMethod>,
+) -> Result<(), String> {
+  let mut ship = match ctx.db.entity().id().find(&entity_id) {
+    Some(ship) => ship,
+    None => return Err(format!("Entity {} not found", entity_id)),
+  };
+  piloting::require_pilot_or_admin(ctx, entity_id)?;
+  if ship.node == destination {
+    return Err("Ship is already at its destination".to_string());
+  }
+
+  let method = method.unwrap_or(if ship.max_impulse.x / ship.mass >= HIGH_THRUST_ACCELERATION {
+    TransferMethod::Brachistochrone
+  } else {
+    TransferMethod::Hohmann
+  });
+
+  let geometry = transfer_geometry(ctx, &ship.node, &destination, &ship)?;
+  let now = ctx.timestamp.to_micros_since_unix_epoch();
+  let earliest_departure = maneuver::ORIENTATION_LEAD + DEPARTURE_MARGIN;
+
+  // (seconds until departure, travel time, burns as (seconds from now, node-frame delta-v))
+  let (wait, travel_time, burns) = match method {
+    TransferMethod::Hohmann => plan_hohmann(&geometry, earliest_departure)?,
+    TransferMethod::Brachistochrone => plan_brachistochrone(
+      &geometry,
+      earliest_departure,
+      ship.max_impulse.x / ship.mass,
+      delta_v_budget(ship.mass, ship.propellant, ship.specific_impulse),
+    )?,
+  };
+
+  let mut maneuver_ids = Vec::new();
+  let mut total_delta_v = 0.0;
+  for (offset, delta_v) in burns {
+    let execute_at = Timestamp::from_micros_since_unix_epoch(now + (offset * 1_000_000.0) as i64);
+    total_delta_v += delta_v.length();
+    maneuver_ids.push(maneuver::schedule_burn(ctx, entity_id, execute_at, delta_v, ManeuverFrame::World)?);
+  }
+
+  // The burns only see the gravity of the ship's own node, so a transfer
+  // around a parent body has to be flown in the parent's frame. The plan
+  // already has the ship orbiting the parent until departure.
+  if geometry.central_body != ship.node {
+    log::info!("Moving entity {} from {:?} to {:?} for the transfer", entity_id, ship.node, geometry.central_body);
+    ship.node = geometry.central_body.clone();
+    ship.relative_position = geometry.departure_position;
+    ship.relative_velocity = geometry.departure_velocity;
+    ctx.db.entity().id().update(ship);
+  }
+
+  let departure_at = Timestamp::from_micros_since_unix_epoch(now + (wait * 1_000_000.0) as i64);
+  let arrival_at = Timestamp::from_micros_since_unix_epoch(now + ((wait + travel_time) * 1_000_000.0) as i64);
+
+  log::info!(
+    "Planned {:?} transfer for entity {}: depart in {:.0}s, {:.1} m/s, {:.0}s travel",
+    method, entity_id, wait, total_delta_v, travel_time,
+  );
+
+  ctx.db.transfer_plan().insert(TransferPlan {
+    id: ids::next(ctx, "transfer_plan"),
+    entity_id,
+    destination,
+    method,
+    departure_at,
+    arrival_at,
+    delta_v: total_delta_v,
+    travel_time,
+    maneuver_ids,
+  });
+  Ok(())
+}
+
+/// Work out which body the transfer orbits and where both ends are relative to it
+fn transfer_geometry(
+  ctx: &ReducerContext,
+  origin: &NodeName,
+  destination: &NodeName,
+  ship: &crate::Entity,
+) -> Result<TransferGeometry, String> {
+  let origin = find_node(ctx, origin).ok_or("Ship's node not found")?;
+  let destination = find_node(ctx, destination).ok_or("Destination node not found")?;
+
+  let (central_body, departure_position, departure_velocity) = if destination.parent_id == Some(origin.id) {
+    // Going to a body that orbits the one we're at - the ship is already in the right frame
+    (origin, ship.relative_position, ship.relative_velocity)
+  } else if destination.parent_id.is_some() && destination.parent_id == origin.parent_id {
+    // Sibling bodies - transfer around the shared parent, departing from the origin body
+    let parent = ctx.db.node().id().find(&destination.parent_id.unwrap()).ok_or("Parent node not found")?;
+    (parent, origin.node_position + ship.relative_position, origin.node_velocity + ship.relative_velocity)
+  } else {
+    return Err(format!("No direct transfer from {:?} to {:?}", origin.name, destination.name));
+  };
+
+  let gravitational_parameter = trajectory::GRAVITATIONAL_CONSTANT * central_body.mass;
+  if gravitational_parameter <= 0.0 {
+    return Err(format!("{:?} has no mass to orbit", central_body.name));
+  }
+
+  Ok(TransferGeometry {
+    central_body: central_body.name,
+    gravitational_parameter,
+    departure_position,
+    departure_velocity,
+    target_position: destination.node_position,
+    target_velocity: destination.node_velocity,
+  })
+}
+
+/// Tsiolkovsky: Δv = Isp⋅g0⋅ln(m0 / m1)
+fn delta_v_budget(mass: f64, propellant: f64, specific_impulse: f64) -> f64 {
+  let dry_mass = mass - propellant;
+  if dry_mass <= 0.0 {
+    return 0.0;
+  }
+  specific_impulse * maneuver::STANDARD_GRAVITY * (mass / dry_mass).ln()
+}
+
+/// Where a body on a circular orbit will be after `time` seconds
+fn propagate_circular(position: DVec3, velocity: DVec3, gravitational_parameter: f64, time: f64) -> (DVec3, DVec3) {
+  let radius = position.length();
+  let mean_motion = (gravitational_parameter / radius.powi(3)).sqrt();
+  let axis = position.cross(&velocity).normalize();
+  let rotation = DQuat::from_axis_angle(axis, mean_motion * time);
+  (rotation * position, rotation * velocity)
+}
+
+fn plan_hohmann(geometry: &TransferGeometry, earliest_departure: f64) -> Result<(f64, f64, Vec<(f64, DVec3)>), String> {
+  let mu = geometry.gravitational_parameter;
+  let r1 = geometry.departure_position.length();
+  let r2 = geometry.target_position.length();
+  if r1 == 0.0 || r2 == 0.0 {
+    return Err("Both ends of a Hohmann transfer must be off the central body".to_string());
+  }
+
+  let axis = geometry.departure_position.cross(&geometry.departure_velocity).normalize();
+  if axis.length() == 0.0 {
+    return Err("Ship is not orbiting - no plane to transfer in".to_string());
+  }
+
+  let transfer_semi_major_axis = (r1 + r2) / 2.0;
+  let travel_time = std::f64::consts::PI * (transfer_semi_major_axis.powi(3) / mu).sqrt();
+  let departure_delta_v = (mu / r1).sqrt() * ((2.0 * r2 / (r1 + r2)).sqrt() - 1.0);
+  let arrival_delta_v = (mu / r2).sqrt() * (1.0 - (2.0 * r1 / (r1 + r2)).sqrt());
+
+  // Target must lead us by this much at departure so it arrives at apoapsis with us
+  let departure_rate = (mu / r1.powi(3)).sqrt();
+  let target_rate = (mu / r2.powi(3)).sqrt();
+  let required_phase = std::f64::consts::PI - target_rate * travel_time;
+  if (departure_rate - target_rate).abs() < 1e-12 {
+    return Err("Orbits are co-rotating - no departure window".to_string());
+  }
+
+  let current_phase = signed_angle(geometry.departure_position, geometry.target_position, axis);
+  let synodic_period = std::f64::consts::TAU / (departure_rate - target_rate).abs();
+  let mut wait = (current_phase - required_phase) / (departure_rate - target_rate);
+  wait = wait.rem_euclid(synodic_period);
+  while wait < earliest_departure {
+    wait += synodic_period;
+  }
+
+  // Burn prograde along our velocity at departure, and along the target's velocity on arrival
+  let (_, departure_velocity) = propagate_circular(geometry.departure_position, geometry.departure_velocity, mu, wait);
+  let (_, target_velocity) = propagate_circular(geometry.target_position, geometry.target_velocity, mu, wait + travel_time);
+
+  let burns = vec![
+    (wait, departure_velocity.normalize() * departure_delta_v),
+    (wait + travel_time, target_velocity.normalize() * arrival_delta_v),
+  ];
+  Ok((wait, travel_time, burns))
+}
+
+fn plan_brachistochrone(
+  geometry: &TransferGeometry,
+  earliest_departure: f64,
+  acceleration: f64,
+  delta_v_budget: f64,
+) -> Result<(f64, f64, Vec<(f64, DVec3)>), String> {
+  if acceleration <= 0.0 {
+    return Err("Ship has no main thrust".to_string());
+  }
+  let mu = geometry.gravitational_parameter;
+  let wait = earliest_departure;
+
+  // Aim where the target will be - refine the arrival time a few times
+  let mut travel_time = 0.0;
+  let mut displacement = DVec3::zero();
+  let mut top_speed = 0.0;
+  for _ in 0..5 {
+    let (target_position, _) = propagate_circular(geometry.target_position, geometry.target_velocity, mu, wait + travel_time);
+    displacement = target_position - geometry.departure_position;
+    let distance = displacement.length();
+
+    // Full flip-and-burn if the propellant allows it, otherwise coast in the middle
+    let full_burn_speed = (distance * acceleration).sqrt();
+    top_speed = full_burn_speed.min(delta_v_budget / 2.0);
+    if top_speed <= 0.0 {
+      return Err("Not enough propellant for a brachistochrone".to_string());
+    }
+    let burn_time = top_speed / acceleration;
+    let coast_distance = distance - top_speed * burn_time;
+    travel_time = 2.0 * burn_time + coast_distance / top_speed;
+  }
+
+  let direction = displacement.normalize();
+  let burn_time = top_speed / acceleration;
+  let burns = vec![
+    (wait, direction * top_speed),
+    (wait + travel_time - burn_time, direction * -top_speed),
+  ];
+  Ok((wait, travel_time, burns))
+}
+
+/// Angle from `from` to `to` around `axis`, in [0, 2π)
+fn signed_angle(from: DVec3, to: DVec3, axis: DVec3) -> f64 {
+  let angle = from.cross(&to).dot(&axis).atan2(from.dot(&to));
+  angle.rem_euclid(std::f64::consts::TAU)
+}
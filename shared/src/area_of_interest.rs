@@ -0,0 +1,38 @@
+//! Space in every node's frame is split into cubic cells, so clients can
+//! subscribe to what is around them with plain range queries on cell
+//! coordinates instead of distances the database can't compute.
+
+use crate::math::DVec3;
+
+/// Edge length of a cell in metres
+pub const CELL_SIZE: f64 = 1_000.0;
+
+/// Integer coordinates of a cell within a node's frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+  pub x: i64,
+  pub y: i64,
+  pub z: i64,
+}
+
+impl Cell {
+  pub fn containing(position: DVec3) -> Self {
+    Self {
+      x: (position.x / CELL_SIZE).floor() as i64,
+      y: (position.y / CELL_SIZE).floor() as i64,
+      z: (position.z / CELL_SIZE).floor() as i64,
+    }
+  }
+
+  /// How many cells either side of a centre cell cover `radius`
+  pub fn span(radius: f64) -> i64 {
+    (radius / CELL_SIZE).ceil() as i64
+  }
+
+  /// Largest per-axis difference in cells
+  pub fn distance(&self, other: &Cell) -> i64 {
+    (self.x - other.x).abs()
+      .max((self.y - other.y).abs())
+      .max((self.z - other.z).abs())
+  }
+}
@@ -0,0 +1,151 @@
+//! The waypoint autopilot the server tick flies ships with.
+//!
+//! Unlike the controllers in `trajectory`, it turns the ship kinematically
+//! (rotation is set, not torqued) and pushes it straight along the line to
+//! the waypoint once it faces it. Ships are stopped dead on arrival.
+
+use crate::math::{DQuat, DVec3};
+use crate::trajectory::DELTA_TIME;
+
+/// Close enough to the waypoint to move on to the next one (m)
+pub const ARRIVAL_TOLERANCE: f64 = 3.0;
+/// Facing the waypoint to within this (rad, ±2.5°) ends the turn
+pub const HEADING_TOLERANCE: f64 = 0.044;
+/// Facing the waypoint to within this (rad, ~10°) the ship starts moving
+pub const ORIENTATION_TOLERANCE: f64 = 0.175;
+/// Top speed on the way to a waypoint (m/s)
+pub const MAX_CRUISE_SPEED: f64 = 2.0;
+/// Top speed within `APPROACH_DISTANCE` of a waypoint (m/s)
+pub const MAX_APPROACH_SPEED: f64 = 1.0;
+/// Distance at which the ship slows to approach speed (m)
+pub const APPROACH_DISTANCE: f64 = 10.0;
+/// Hull assumed for ships not built from a class: width (x), height (y),
+/// length (z) in metres, like ship class dimensions
+pub const DEFAULT_SHIP_DIMENSIONS: DVec3 = DVec3 { x: 8.0, y: 6.0, z: 10.0 };
+
+/// What the autopilot reads and writes of a ship
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutopilotState {
+  pub position: DVec3,
+  pub velocity: DVec3,
+  pub rotation: DQuat,
+  pub rotational_velocity: DVec3,
+}
+
+/// Average moment of inertia of a solid box, over its three axes
+pub fn box_moment_of_inertia(mass: f64, dimensions: DVec3) -> f64 {
+  let (width, height, length) = (dimensions.x, dimensions.y, dimensions.z);
+  let pitch = mass * (width * width + height * height) / 12.0;
+  let yaw = mass * (length * length + height * height) / 12.0;
+  let roll = mass * (length * length + width * width) / 12.0;
+  (pitch + yaw + roll) / 3.0
+}
+
+/// Fly one tick toward `target`, turning with the nav thrusters against
+/// `moment_of_inertia`. Returns true once the ship started the tick within
+/// `ARRIVAL_TOLERANCE` of it, at which point it has been stopped.
+pub fn step(state: &mut AutopilotState, target: DVec3, mass: f64, moment_of_inertia: f64, max_impulse: DVec3) -> bool {
+  let displacement = target - state.position;
+  let distance = displacement.length();
+
+  // Right on top of it there's no direction to turn to - just stop
+  if distance < 0.1 {
+    state.velocity = DVec3::ZERO;
+    return true;
+  }
+  let target_direction = displacement * (1.0 / distance);
+
+  // Only move when properly oriented at the start of the tick
+  let rotation_diff = turn_toward(state, target_direction, moment_of_inertia, max_impulse.z);
+  if rotation_diff < ORIENTATION_TOLERANCE {
+    thrust_toward(state, target_direction, distance, mass, max_impulse);
+  }
+
+  let arrived = distance < ARRIVAL_TOLERANCE;
+  if arrived {
+    // Stop the ship to prevent overshoot
+    state.velocity = DVec3::ZERO;
+  }
+  arrived
+}
+
+/// Rotate toward the target direction, accelerating through the first half
+/// of the turn and braking through the second. Returns the angle that was
+/// left to turn before this step.
+fn turn_toward(state: &mut AutopilotState, target_direction: DVec3, moment_of_inertia: f64, nav_thrust: f64) -> f64 {
+  let target_rotation = DQuat::from_rotation_arc(DVec3::FORWARD, target_direction);
+  let current_rotation = state.rotation;
+  let rotation_diff = current_rotation.angle_between(&target_rotation);
+
+  let max_angular_acceleration = nav_thrust / moment_of_inertia; // rad/s²
+  let current_angular_speed = state.rotational_velocity.length();
+
+  // Time and angle needed to stop the current spin
+  let time_to_stop = if max_angular_acceleration > 0.0 {
+    current_angular_speed / max_angular_acceleration
+  } else {
+    0.0
+  };
+  let stop_distance = current_angular_speed * time_to_stop - 0.5 * max_angular_acceleration * time_to_stop * time_to_stop;
+  let halfway_point = rotation_diff / 2.0;
+
+  let target_angular_velocity = if rotation_diff <= stop_distance + HEADING_TOLERANCE {
+    // Decelerate
+    (current_angular_speed - max_angular_acceleration * DELTA_TIME).max(0.0)
+  } else if rotation_diff > halfway_point {
+    // First half - accelerate up to the maximum safe velocity
+    let max_safe_velocity = (max_angular_acceleration * rotation_diff).sqrt();
+    (current_angular_speed + max_angular_acceleration * DELTA_TIME).min(max_safe_velocity)
+  } else {
+    // Second half - start decelerating
+    let max_safe_velocity = (2.0 * max_angular_acceleration * rotation_diff).sqrt();
+    current_angular_speed.min(max_safe_velocity)
+  };
+
+  // Proportion of the remaining rotation to complete this step
+  let rotation_step = target_angular_velocity * DELTA_TIME;
+  state.rotation = current_rotation.slerp(&target_rotation, (rotation_step / rotation_diff).min(1.0));
+
+  state.rotational_velocity = if rotation_diff < HEADING_TOLERANCE {
+    // Completely stop rotating once the target is reached
+    DVec3::ZERO
+  } else {
+    current_rotation.axis_to(&target_rotation) * target_angular_velocity
+  };
+  rotation_diff
+}
+
+/// Accelerate or brake along the line to the target toward a speed that
+/// shrinks with the remaining distance
+fn thrust_toward(state: &mut AutopilotState, target_direction: DVec3, distance: f64, mass: f64, max_impulse: DVec3) {
+  let main_thrust = max_impulse.x;
+  let retro_thrust = max_impulse.y;
+  let current_speed = state.velocity.length();
+
+  let current_max_speed = if distance < APPROACH_DISTANCE {
+    MAX_APPROACH_SPEED
+  } else {
+    MAX_CRUISE_SPEED
+  };
+  let desired_speed = if distance < 1.0 {
+    0.0 // Stop when very close
+  } else {
+    // Speed increases with distance up to the max, reached at 20m
+    current_max_speed * (distance / 20.0).min(1.0)
+  };
+
+  let speed_error = desired_speed - current_speed;
+  let thrust_magnitude = if speed_error.abs() < 0.1 {
+    // Close to the desired speed - gentle thrust for stability
+    main_thrust * 0.1
+  } else if speed_error > 0.0 {
+    main_thrust * (speed_error / current_max_speed).min(1.0)
+  } else {
+    // Retro thrust pushes back along the line, braking
+    -retro_thrust * (-speed_error / current_max_speed).min(1.0)
+  };
+
+  let new_velocity = state.velocity + target_direction * (thrust_magnitude / mass * DELTA_TIME);
+  state.position += (state.velocity + new_velocity) * (0.5 * DELTA_TIME);
+  state.velocity = new_velocity;
+}
@@ -0,0 +1,200 @@
+//! Physical and orbital data for every named body in `NodeName`.
+//!
+//! Planet and Pluto elements are JPL's J2000 mean elements (Standish) for the
+//! ecliptic. Dwarf-planet angles and all moon elements are approximate, with
+//! moons given relative to their parent's equator where it matters. Good
+//! enough to start in a recognisable solar system, not for ephemerides.
+
+use crate::math::{DQuat, DVec3};
+use crate::trajectory::GRAVITATIONAL_CONSTANT;
+use crate::NodeName;
+
+/// Metres per astronomical unit
+pub const ASTRONOMICAL_UNIT: f64 = 1.495_978_707e11;
+
+const KM: f64 = 1_000.0;
+const HOUR: f64 = 3_600.0;
+
+/// Keplerian orbital elements at the catalog epoch (J2000).
+/// Lengths in metres, angles in degrees as they appear in the sources.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrbitalElements {
+  pub semi_major_axis: f64,
+  pub eccentricity: f64,
+  pub inclination: f64,
+  pub longitude_of_ascending_node: f64,
+  pub argument_of_periapsis: f64,
+  pub mean_anomaly: f64,
+}
+
+/// One entry in the catalog
+#[derive(Clone, Debug, PartialEq)]
+pub struct Body {
+  pub name: NodeName,
+  pub parent: Option<NodeName>,
+  pub mass: f64,   // kg
+  pub radius: f64, // m, mean
+  /// Sidereal rotation period in seconds, negative for retrograde spin, zero if chaotic
+  pub rotation_period: f64,
+  pub orbit: Option<OrbitalElements>,
+}
+
+impl OrbitalElements {
+  /// Planet elements in the JPL form: a (AU), e, i, mean longitude L,
+  /// longitude of perihelion ϖ and longitude of ascending node Ω (degrees)
+  const fn from_longitudes(a_au: f64, e: f64, i: f64, l: f64, varpi: f64, node: f64) -> Self {
+    Self {
+      semi_major_axis: a_au * ASTRONOMICAL_UNIT,
+      eccentricity: e,
+      inclination: i,
+      longitude_of_ascending_node: node,
+      argument_of_periapsis: varpi - node,
+      mean_anomaly: l - varpi,
+    }
+  }
+
+  const fn new(a: f64, e: f64, i: f64, node: f64, periapsis: f64, mean_anomaly: f64) -> Self {
+    Self {
+      semi_major_axis: a,
+      eccentricity: e,
+      inclination: i,
+      longitude_of_ascending_node: node,
+      argument_of_periapsis: periapsis,
+      mean_anomaly,
+    }
+  }
+
+  /// Orbital period around a body with gravitational parameter μ
+  pub fn period(&self, gravitational_parameter: f64) -> f64 {
+    std::f64::consts::TAU * (self.semi_major_axis.powi(3) / gravitational_parameter).sqrt()
+  }
+
+  /// Position and velocity relative to the parent at the epoch, in world axes
+  pub fn state_vector(&self, gravitational_parameter: f64) -> (DVec3, DVec3) {
+    let a = self.semi_major_axis;
+    let e = self.eccentricity;
+    let mean_anomaly = self.mean_anomaly.to_radians().rem_euclid(std::f64::consts::TAU);
+
+    // Kepler's equation M = E - e⋅sin(E), solved by Newton's method
+    let mut eccentric_anomaly = if e < 0.8 { mean_anomaly } else { std::f64::consts::PI };
+    for _ in 0..50 {
+      let step = (eccentric_anomaly - e * eccentric_anomaly.sin() - mean_anomaly) / (1.0 - e * eccentric_anomaly.cos());
+      eccentric_anomaly -= step;
+      if step.abs() < 1e-14 {
+        break;
+      }
+    }
+
+    // Perifocal frame: x towards periapsis, y along the direction of motion
+    let (sin_e, cos_e) = eccentric_anomaly.sin_cos();
+    let semi_minor = (1.0 - e * e).sqrt();
+    let radius = a * (1.0 - e * cos_e);
+    let speed_factor = (gravitational_parameter * a).sqrt() / radius;
+    let position = DVec3::new(a * (cos_e - e), a * semi_minor * sin_e, 0.0);
+    let velocity = DVec3::new(-speed_factor * sin_e, speed_factor * semi_minor * cos_e, 0.0);
+
+    // Perifocal -> ecliptic: Rz(Ω) Rx(i) Rz(ω)
+    let orientation = DQuat::from_axis_angle(DVec3::Z, self.longitude_of_ascending_node.to_radians())
+      * DQuat::from_axis_angle(DVec3::X, self.inclination.to_radians())
+      * DQuat::from_axis_angle(DVec3::Z, self.argument_of_periapsis.to_radians());
+
+    (ecliptic_to_world(orientation * position), ecliptic_to_world(orientation * velocity))
+  }
+}
+
+/// Ecliptic coordinates have +Z to the north; the world is Y-up like Bevy
+pub fn ecliptic_to_world(v: DVec3) -> DVec3 {
+  DVec3::new(v.x, v.z, -v.y)
+}
+
+impl Body {
+  /// Gravitational parameter μ = GM
+  pub fn gravitational_parameter(&self) -> f64 {
+    GRAVITATIONAL_CONSTANT * self.mass
+  }
+
+  /// Spin about the world Y axis
+  pub fn rotational_velocity(&self) -> DVec3 {
+    if self.rotation_period == 0.0 {
+      return DVec3::ZERO;
+    }
+    DVec3::Y * (std::f64::consts::TAU / self.rotation_period)
+  }
+}
+
+/// Look up a body by name; `Custom` nodes are never in the catalog
+pub fn find(name: &NodeName) -> Option<Body> {
+  bodies().into_iter().find(|body| body.name == *name)
+}
+
+/// Every named body, parents before their children
+pub fn bodies() -> Vec<Body> {
+  use NodeName::*;
+
+  let star = |name, mass, radius: f64, rotation_hours: f64| Body {
+    name,
+    parent: None,
+    mass,
+    radius: radius * KM,
+    rotation_period: rotation_hours * HOUR,
+    orbit: None,
+  };
+  let body = |name, parent, mass, radius: f64, rotation_hours: f64, orbit| Body {
+    name,
+    parent: Some(parent),
+    mass,
+    radius: radius * KM,
+    rotation_period: rotation_hours * HOUR,
+    orbit: Some(orbit),
+  };
+  let planet = OrbitalElements::from_longitudes;
+  let moon = |a_km: f64, e, i, node, periapsis, mean_anomaly| {
+    OrbitalElements::new(a_km * KM, e, i, node, periapsis, mean_anomaly)
+  };
+  let minor = |a_au: f64, e, i, node, periapsis, mean_anomaly| {
+    OrbitalElements::new(a_au * ASTRONOMICAL_UNIT, e, i, node, periapsis, mean_anomaly)
+  };
+
+  vec![
+    star(Sun, 1.988_47e30, 695_700.0, 609.12),
+    // Planets: a (AU), e, i, L, ϖ, Ω
+    body(Mercury, Sun, 3.3011e23, 2_439.7, 1_407.6, planet(0.387_099_27, 0.205_635_93, 7.004_979_02, 252.250_323_50, 77.457_796_28, 48.330_765_93)),
+    body(Venus, Sun, 4.8675e24, 6_051.8, -5_832.5, planet(0.723_335_66, 0.006_776_72, 3.394_676_05, 181.979_099_50, 131.602_467_18, 76.679_842_55)),
+    body(Earth, Sun, 5.972_37e24, 6_371.0, 23.9345, planet(1.000_002_61, 0.016_711_23, -0.000_015_31, 100.464_571_66, 102.937_681_93, 0.0)),
+    body(Mars, Sun, 6.4171e23, 3_389.5, 24.6229, planet(1.523_710_34, 0.093_394_10, 1.849_691_42, -4.553_432_05, -23.943_629_59, 49.559_538_91)),
+    body(Jupiter, Sun, 1.8982e27, 69_911.0, 9.925, planet(5.202_887_00, 0.048_386_24, 1.304_396_95, 34.396_440_51, 14.728_479_83, 100.473_909_09)),
+    body(Saturn, Sun, 5.6834e26, 58_232.0, 10.656, planet(9.536_675_94, 0.053_861_79, 2.485_991_87, 49.954_244_23, 92.598_878_31, 113.662_424_48)),
+    body(Uranus, Sun, 8.6810e25, 25_362.0, -17.24, planet(19.189_164_64, 0.047_257_44, 0.772_637_83, 313.238_104_51, 170.954_276_30, 74.016_925_03)),
+    body(Neptune, Sun, 1.024_13e26, 24_622.0, 16.11, planet(30.069_922_76, 0.008_590_48, 1.770_043_47, -55.120_029_69, 44.964_762_27, 131.784_225_74)),
+    body(Pluto, Sun, 1.303e22, 1_188.3, -153.2928, planet(39.482_116_75, 0.248_827_30, 17.140_012_06, 238.929_038_33, 224.068_916_29, 110.303_936_84)),
+    // Dwarf planets: a (AU), e, i, Ω, ω, M
+    body(Ceres, Sun, 9.3835e20, 469.7, 9.074, minor(2.7675, 0.0758, 10.593, 80.305, 73.597, 6.0)),
+    body(Eris, Sun, 1.6466e22, 1_163.0, 378.9, minor(67.86, 0.4407, 44.04, 35.95, 151.64, 205.0)),
+    body(Haumea, Sun, 4.006e21, 798.0, 3.915, minor(43.13, 0.195, 28.21, 122.17, 238.78, 198.0)),
+    body(Makemake, Sun, 3.1e21, 715.0, 22.83, minor(45.79, 0.159, 28.98, 79.62, 294.83, 150.0)),
+    // Moons: a (km), e, i, Ω, ω, M
+    body(Moon, Earth, 7.342e22, 1_737.4, 655.72, moon(384_399.0, 0.0549, 5.145, 125.08, 318.15, 135.27)),
+    body(Io, Jupiter, 8.931_938e22, 1_821.6, 42.459, moon(421_700.0, 0.0041, 0.05, 43.98, 84.13, 342.02)),
+    body(Europa, Jupiter, 4.799_844e22, 1_560.8, 85.228, moon(671_034.0, 0.009, 0.47, 219.11, 88.97, 171.02)),
+    body(Ganymede, Jupiter, 1.4819e23, 2_634.1, 171.709, moon(1_070_412.0, 0.0013, 0.20, 63.55, 192.42, 317.54)),
+    body(Callisto, Jupiter, 1.075_938e23, 2_410.3, 400.536, moon(1_882_709.0, 0.0074, 0.192, 298.85, 52.64, 181.41)),
+    body(Mimas, Saturn, 3.7493e19, 198.2, 22.6, moon(185_539.0, 0.0196, 1.574, 173.03, 332.50, 14.85)),
+    body(Enceladus, Saturn, 1.080_22e20, 252.1, 32.885, moon(237_948.0, 0.0047, 0.009, 342.51, 0.08, 199.69)),
+    body(Tethys, Saturn, 6.174_49e20, 531.1, 45.307, moon(294_619.0, 0.0001, 1.12, 259.84, 45.20, 243.37)),
+    body(Dione, Saturn, 1.095_452e21, 561.4, 65.686, moon(377_396.0, 0.0022, 0.019, 290.41, 284.32, 322.23)),
+    body(Rhea, Saturn, 2.306_518e21, 763.8, 108.438, moon(527_108.0, 0.001_258, 0.345, 351.04, 241.62, 179.78)),
+    body(Titan, Saturn, 1.3452e23, 2_574.73, 382.69, moon(1_221_870.0, 0.0288, 0.348_54, 28.06, 180.53, 163.31)),
+    // Hyperion tumbles chaotically - no steady spin
+    body(Hyperion, Saturn, 5.6199e18, 135.0, 0.0, moon(1_481_010.0, 0.123_006_1, 0.43, 263.85, 303.18, 86.34)),
+    body(Iapetus, Saturn, 1.805_635e21, 734.5, 1_903.94, moon(3_560_820.0, 0.028_612_5, 15.47, 81.11, 271.61, 201.79)),
+    body(Phoebe, Saturn, 8.292e18, 106.5, 9.2735, moon(12_960_000.0, 0.156_241_5, 175.3, 241.57, 345.58, 53.04)),
+    body(Miranda, Uranus, 6.4e19, 235.8, 33.923, moon(129_390.0, 0.0013, 4.232, 326.44, 68.31, 311.33)),
+    body(Ariel, Uranus, 1.251e21, 578.9, 60.489, moon(191_020.0, 0.0012, 0.260, 22.39, 115.35, 39.48)),
+    body(Umbriel, Uranus, 1.275e21, 584.7, 99.460, moon(266_000.0, 0.0039, 0.128, 33.49, 84.71, 12.47)),
+    body(Titania, Uranus, 3.400e21, 788.4, 208.94, moon(435_910.0, 0.0011, 0.340, 99.77, 284.40, 24.61)),
+    body(Oberon, Uranus, 3.076e21, 761.4, 323.118, moon(583_520.0, 0.0014, 0.058, 279.77, 104.40, 283.09)),
+    // Triton orbits retrograde and keeps one face to Neptune
+    body(Triton, Neptune, 2.139e22, 1_353.4, -141.045, moon(354_759.0, 0.000_016, 156.885, 177.61, 66.14, 352.26)),
+    body(Charon, Pluto, 1.586e21, 606.0, -153.2928, moon(19_591.0, 0.000_05, 0.08, 223.05, 146.11, 131.07)),
+  ]
+}
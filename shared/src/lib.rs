@@ -1,5 +1,17 @@
-pub enum Node {
+pub mod area_of_interest;
+pub mod autopilot;
+pub mod catalog;
+pub mod math;
+pub mod piloting;
+pub mod trajectory;
+
+/// Names of the bodies and frames in the node hierarchy
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeName {
   Sun,
+  Mercury,
   Earth,
   Mars,
   Venus,
@@ -8,64 +20,153 @@ pub enum Node {
   Uranus,
   Neptune,
   Pluto,
+  Moon,
+  Ceres,
+  Eris,
+  Haumea,
+  Makemake,
+  Phoebe,
+  Titan,
+  Callisto,
+  Ganymede,
+  Io,
+  Europa,
+  Enceladus,
+  Triton,
+  Charon,
+  Titania,
+  Oberon,
+  Rhea,
+  Iapetus,
+  Dione,
+  Tethys,
+  Mimas,
+  Hyperion,
+  Ariel,
+  Umbriel,
+  Miranda,
   Custom(String),
 }
-impl Node {
+
+impl NodeName {
   pub fn as_str(&self) -> &str {
     match self {
-      Node::Sun => "Sun",
-      Node::Earth => "Earth",
-      Node::Mars => "Mars",
-      Node::Venus => "Venus",
-      Node::Jupiter => "Jupiter",
-      Node::Saturn => "Saturn",
-      Node::Uranus => "Uranus",
-      Node::Neptune => "Neptune",
-      Node::Pluto => "Pluto",
-      Node::Custom(name) => name,
+      NodeName::Sun => "Sun",
+      NodeName::Mercury => "Mercury",
+      NodeName::Earth => "Earth",
+      NodeName::Mars => "Mars",
+      NodeName::Venus => "Venus",
+      NodeName::Jupiter => "Jupiter",
+      NodeName::Saturn => "Saturn",
+      NodeName::Uranus => "Uranus",
+      NodeName::Neptune => "Neptune",
+      NodeName::Pluto => "Pluto",
+      NodeName::Moon => "Moon",
+      NodeName::Ceres => "Ceres",
+      NodeName::Eris => "Eris",
+      NodeName::Haumea => "Haumea",
+      NodeName::Makemake => "Makemake",
+      NodeName::Phoebe => "Phoebe",
+      NodeName::Titan => "Titan",
+      NodeName::Callisto => "Callisto",
+      NodeName::Ganymede => "Ganymede",
+      NodeName::Io => "Io",
+      NodeName::Europa => "Europa",
+      NodeName::Enceladus => "Enceladus",
+      NodeName::Triton => "Triton",
+      NodeName::Charon => "Charon",
+      NodeName::Titania => "Titania",
+      NodeName::Oberon => "Oberon",
+      NodeName::Rhea => "Rhea",
+      NodeName::Iapetus => "Iapetus",
+      NodeName::Dione => "Dione",
+      NodeName::Tethys => "Tethys",
+      NodeName::Mimas => "Mimas",
+      NodeName::Hyperion => "Hyperion",
+      NodeName::Ariel => "Ariel",
+      NodeName::Umbriel => "Umbriel",
+      NodeName::Miranda => "Miranda",
+      NodeName::Custom(name) => name,
     }
   }
 }
 
-impl std::fmt::Display for Node {
+impl std::fmt::Display for NodeName {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "{}", self.as_str())
   }
 }
 
-impl std::str::FromStr for Node {
+impl std::str::FromStr for NodeName {
   type Err = String;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     match s {
-      "Sun" => Ok(Node::Sun),
-      "Earth" => Ok(Node::Earth),
-      "Mars" => Ok(Node::Mars),
-      "Venus" => Ok(Node::Venus),
-      "Jupiter" => Ok(Node::Jupiter),
-      "Saturn" => Ok(Node::Saturn),
-      "Uranus" => Ok(Node::Uranus),
-      "Neptune" => Ok(Node::Neptune),
-      "Pluto" => Ok(Node::Pluto),
-      _ => Ok(Node::Custom(s.to_string())),
+      "Sun" => Ok(NodeName::Sun),
+      "Mercury" => Ok(NodeName::Mercury),
+      "Earth" => Ok(NodeName::Earth),
+      "Mars" => Ok(NodeName::Mars),
+      "Venus" => Ok(NodeName::Venus),
+      "Jupiter" => Ok(NodeName::Jupiter),
+      "Saturn" => Ok(NodeName::Saturn),
+      "Uranus" => Ok(NodeName::Uranus),
+      "Neptune" => Ok(NodeName::Neptune),
+      "Pluto" => Ok(NodeName::Pluto),
+      "Moon" => Ok(NodeName::Moon),
+      "Ceres" => Ok(NodeName::Ceres),
+      "Eris" => Ok(NodeName::Eris),
+      "Haumea" => Ok(NodeName::Haumea),
+      "Makemake" => Ok(NodeName::Makemake),
+      "Phoebe" => Ok(NodeName::Phoebe),
+      "Titan" => Ok(NodeName::Titan),
+      "Callisto" => Ok(NodeName::Callisto),
+      "Ganymede" => Ok(NodeName::Ganymede),
+      "Io" => Ok(NodeName::Io),
+      "Europa" => Ok(NodeName::Europa),
+      "Enceladus" => Ok(NodeName::Enceladus),
+      "Triton" => Ok(NodeName::Triton),
+      "Charon" => Ok(NodeName::Charon),
+      "Titania" => Ok(NodeName::Titania),
+      "Oberon" => Ok(NodeName::Oberon),
+      "Rhea" => Ok(NodeName::Rhea),
+      "Iapetus" => Ok(NodeName::Iapetus),
+      "Dione" => Ok(NodeName::Dione),
+      "Tethys" => Ok(NodeName::Tethys),
+      "Mimas" => Ok(NodeName::Mimas),
+      "Hyperion" => Ok(NodeName::Hyperion),
+      "Ariel" => Ok(NodeName::Ariel),
+      "Umbriel" => Ok(NodeName::Umbriel),
+      "Miranda" => Ok(NodeName::Miranda),
+      _ => Ok(NodeName::Custom(s.to_string())),
     }
   }
 }
 
+/// What an entity is. The server's set of kinds - the old client-only
+/// `Container` is gone, containers are `Custom("Container")` now.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EntityType {
-  Ship,
+  Star,
   Planet,
+  Moon,
   Asteroid,
-  Container,
+  Comet,
+  Ship,
+  Custom(String),
 }
 
 impl EntityType {
   pub fn as_str(&self) -> &str {
     match self {
-      EntityType::Ship => "Ship",
+      EntityType::Star => "Star",
       EntityType::Planet => "Planet",
+      EntityType::Moon => "Moon",
       EntityType::Asteroid => "Asteroid",
-      EntityType::Container => "Container",
+      EntityType::Comet => "Comet",
+      EntityType::Ship => "Ship",
+      EntityType::Custom(name) => name,
     }
   }
 }
@@ -76,16 +177,19 @@ impl std::fmt::Display for EntityType {
   }
 }
 
+/// Only the built-in kinds parse, `Custom` has to be built explicitly
 impl std::str::FromStr for EntityType {
   type Err = String;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     match s {
-      "Ship" => Ok(EntityType::Ship),
+      "Star" => Ok(EntityType::Star),
       "Planet" => Ok(EntityType::Planet),
+      "Moon" => Ok(EntityType::Moon),
       "Asteroid" => Ok(EntityType::Asteroid),
-      "Container" => Ok(EntityType::Container),
+      "Comet" => Ok(EntityType::Comet),
+      "Ship" => Ok(EntityType::Ship),
       _ => Err(format!("Unknown entity type: {}", s)),
     }
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,427 @@
+//! f64 vector and quaternion math shared by the server solvers and the client.
+//!
+//! Conventions follow Bevy: right-handed, Y up, and -Z is forward for an
+//! unrotated entity. Quaternions are unit quaternions stored as (x, y, z, w).
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DVec3 {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64,
+}
+
+impl DVec3 {
+  pub const ZERO: DVec3 = DVec3 { x: 0.0, y: 0.0, z: 0.0 };
+  pub const X: DVec3 = DVec3 { x: 1.0, y: 0.0, z: 0.0 };
+  pub const Y: DVec3 = DVec3 { x: 0.0, y: 1.0, z: 0.0 };
+  pub const Z: DVec3 = DVec3 { x: 0.0, y: 0.0, z: 1.0 };
+  /// Forward for an unrotated entity
+  pub const FORWARD: DVec3 = DVec3 { x: 0.0, y: 0.0, z: -1.0 };
+
+  pub const fn new(x: f64, y: f64, z: f64) -> Self {
+    Self { x, y, z }
+  }
+
+  pub const fn zero() -> Self {
+    Self::ZERO
+  }
+
+  pub fn length(&self) -> f64 {
+    self.length_squared().sqrt()
+  }
+
+  pub fn length_squared(&self) -> f64 {
+    self.dot(self)
+  }
+
+  /// Unit vector in the same direction, or zero for a zero-length vector
+  pub fn normalize(&self) -> Self {
+    let len = self.length();
+    if len > 0.0 {
+      *self / len
+    } else {
+      Self::ZERO
+    }
+  }
+
+  pub fn dot(&self, other: &DVec3) -> f64 {
+    self.x * other.x + self.y * other.y + self.z * other.z
+  }
+
+  pub fn cross(&self, other: &DVec3) -> DVec3 {
+    DVec3::new(
+      self.y * other.z - self.z * other.y,
+      self.z * other.x - self.x * other.z,
+      self.x * other.y - self.y * other.x,
+    )
+  }
+
+  /// Whether every component is a finite number
+  pub fn is_finite(&self) -> bool {
+    self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+  }
+
+  pub fn distance(&self, other: &DVec3) -> f64 {
+    (*other - *self).length()
+  }
+
+  pub fn lerp(&self, other: &DVec3, t: f64) -> DVec3 {
+    *self + (*other - *self) * t
+  }
+
+  /// Some unit vector perpendicular to this one
+  pub fn any_orthogonal(&self) -> DVec3 {
+    let axis = if self.x.abs() < 0.9 { DVec3::X } else { DVec3::Y };
+    self.cross(&axis).normalize()
+  }
+}
+
+impl std::ops::Add for DVec3 {
+  type Output = DVec3;
+  fn add(self, rhs: DVec3) -> DVec3 {
+    DVec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+  }
+}
+
+impl std::ops::Sub for DVec3 {
+  type Output = DVec3;
+  fn sub(self, rhs: DVec3) -> DVec3 {
+    DVec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+  }
+}
+
+impl std::ops::Neg for DVec3 {
+  type Output = DVec3;
+  fn neg(self) -> DVec3 {
+    DVec3::new(-self.x, -self.y, -self.z)
+  }
+}
+
+impl std::ops::Mul<f64> for DVec3 {
+  type Output = DVec3;
+  fn mul(self, rhs: f64) -> DVec3 {
+    DVec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+  }
+}
+
+impl std::ops::Mul<DVec3> for f64 {
+  type Output = DVec3;
+  fn mul(self, rhs: DVec3) -> DVec3 {
+    rhs * self
+  }
+}
+
+impl std::ops::Div<f64> for DVec3 {
+  type Output = DVec3;
+  fn div(self, rhs: f64) -> DVec3 {
+    DVec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+  }
+}
+
+impl std::ops::AddAssign for DVec3 {
+  fn add_assign(&mut self, rhs: DVec3) {
+    self.x += rhs.x;
+    self.y += rhs.y;
+    self.z += rhs.z;
+  }
+}
+
+impl std::ops::SubAssign for DVec3 {
+  fn sub_assign(&mut self, rhs: DVec3) {
+    self.x -= rhs.x;
+    self.y -= rhs.y;
+    self.z -= rhs.z;
+  }
+}
+
+impl std::ops::MulAssign<f64> for DVec3 {
+  fn mul_assign(&mut self, rhs: f64) {
+    self.x *= rhs;
+    self.y *= rhs;
+    self.z *= rhs;
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DQuat {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64,
+  pub w: f64,
+}
+
+impl DQuat {
+  pub const IDENTITY: DQuat = DQuat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+  pub const fn from_xyzw(x: f64, y: f64, z: f64, w: f64) -> Self {
+    Self { x, y, z, w }
+  }
+
+  /// Rotation of `angle` radians about `axis` (right-hand rule)
+  pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+    let axis = axis.normalize();
+    let (sin, cos) = (angle * 0.5).sin_cos();
+    Self::from_xyzw(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+  }
+
+  /// Axis and angle in radians (0..=π); the axis is +Z for no rotation
+  pub fn to_axis_angle(&self) -> (DVec3, f64) {
+    // Take the shorter of the two equivalent rotations
+    let q = if self.w < 0.0 { -*self } else { *self };
+    let sin_half_angle = DVec3::new(q.x, q.y, q.z).length();
+    if sin_half_angle < 1e-9 {
+      return (DVec3::Z, 0.0);
+    }
+    let axis = DVec3::new(q.x, q.y, q.z) / sin_half_angle;
+    (axis, 2.0 * sin_half_angle.atan2(q.w))
+  }
+
+  /// Shortest rotation that turns unit vector `from` onto unit vector `to`
+  pub fn from_rotation_arc(from: DVec3, to: DVec3) -> Self {
+    let dot = from.dot(&to);
+
+    if dot >= 0.99999 {
+      // Vectors are essentially the same - no rotation needed
+      return Self::IDENTITY;
+    }
+
+    if dot <= -0.99999 {
+      // Vectors are opposite - 180 degree rotation around any perpendicular axis
+      let perp = from.any_orthogonal();
+      return Self::from_xyzw(perp.x, perp.y, perp.z, 0.0);
+    }
+
+    let cross = from.cross(&to);
+    Self::from_xyzw(cross.x, cross.y, cross.z, 1.0 + dot).normalize()
+  }
+
+  /// Rotation that points the entity's forward (-Z) along `forward` with
+  /// its up (+Y) as close to `up` as possible. Falls back to the shortest
+  /// arc when `up` is parallel to `forward`.
+  pub fn look_rotation(forward: DVec3, up: DVec3) -> Self {
+    let back = -forward.normalize();
+    let right = up.cross(&back);
+    if right.length_squared() < 1e-12 {
+      return Self::from_rotation_arc(DVec3::FORWARD, forward.normalize());
+    }
+    let right = right.normalize();
+    let up = back.cross(&right);
+    Self::from_basis(right, up, back)
+  }
+
+  /// Rotation whose columns are the given orthonormal basis vectors
+  fn from_basis(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+    let (m00, m01, m02) = (x_axis.x, y_axis.x, z_axis.x);
+    let (m10, m11, m12) = (x_axis.y, y_axis.y, z_axis.y);
+    let (m20, m21, m22) = (x_axis.z, y_axis.z, z_axis.z);
+    let trace = m00 + m11 + m22;
+
+    let q = if trace > 0.0 {
+      let s = (trace + 1.0).sqrt() * 2.0;
+      Self::from_xyzw((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, 0.25 * s)
+    } else if m00 > m11 && m00 > m22 {
+      let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+      Self::from_xyzw(0.25 * s, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+    } else if m11 > m22 {
+      let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+      Self::from_xyzw((m01 + m10) / s, 0.25 * s, (m12 + m21) / s, (m02 - m20) / s)
+    } else {
+      let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+      Self::from_xyzw((m02 + m20) / s, (m12 + m21) / s, 0.25 * s, (m10 - m01) / s)
+    };
+    q.normalize()
+  }
+
+  pub fn dot(&self, other: &DQuat) -> f64 {
+    self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+  }
+
+  pub fn length(&self) -> f64 {
+    self.dot(self).sqrt()
+  }
+
+  /// Unit quaternion, or identity for a zero-length one
+  pub fn normalize(&self) -> Self {
+    let len = self.length();
+    if len > 0.0 {
+      Self::from_xyzw(self.x / len, self.y / len, self.z / len, self.w / len)
+    } else {
+      Self::IDENTITY
+    }
+  }
+
+  pub fn conjugate(&self) -> Self {
+    Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+  }
+
+  pub fn inverse(&self) -> Self {
+    let norm_sq = self.dot(self);
+    Self::from_xyzw(-self.x / norm_sq, -self.y / norm_sq, -self.z / norm_sq, self.w / norm_sq)
+  }
+
+  /// Rotate a vector: q * v * q⁻¹
+  pub fn rotate(&self, v: DVec3) -> DVec3 {
+    let u = DVec3::new(self.x, self.y, self.z);
+    let t = u.cross(&v) * 2.0;
+    v + t * self.w + u.cross(&t)
+  }
+
+  /// Smallest angle in radians between the two orientations
+  pub fn angle_between(&self, other: &DQuat) -> f64 {
+    // Clamp to avoid numerical errors, |dot| because q and -q are the same rotation
+    2.0 * self.dot(other).abs().min(1.0).acos()
+  }
+
+  /// Axis, in this rotation's local frame, of the rotation that turns it into `other`
+  pub fn axis_to(&self, other: &DQuat) -> DVec3 {
+    let relative = self.inverse() * *other;
+    let sin_half_angle = DVec3::new(relative.x, relative.y, relative.z).length();
+    if sin_half_angle < 1e-6 {
+      // No significant rotation
+      return DVec3::Z;
+    }
+    DVec3::new(relative.x, relative.y, relative.z) / sin_half_angle
+  }
+
+  /// Spherical linear interpolation along the shorter path
+  pub fn slerp(&self, other: &DQuat, t: f64) -> Self {
+    let mut dot = self.dot(other);
+
+    // If dot product is negative, negate one quaternion to take shorter path
+    let other = if dot < 0.0 {
+      dot = -dot;
+      -*other
+    } else {
+      *other
+    };
+
+    // If quaternions are very close, use linear interpolation
+    if dot > 0.9995 {
+      return Self::from_xyzw(
+        self.x + t * (other.x - self.x),
+        self.y + t * (other.y - self.y),
+        self.z + t * (other.z - self.z),
+        self.w + t * (other.w - self.w),
+      )
+      .normalize();
+    }
+
+    let theta = dot.acos();
+    let sin_theta = theta.sin();
+    let t1 = ((1.0 - t) * theta).sin() / sin_theta;
+    let t2 = (t * theta).sin() / sin_theta;
+
+    Self::from_xyzw(
+      t1 * self.x + t2 * other.x,
+      t1 * self.y + t2 * other.y,
+      t1 * self.z + t2 * other.z,
+      t1 * self.w + t2 * other.w,
+    )
+  }
+}
+
+impl std::ops::Mul for DQuat {
+  type Output = DQuat;
+  /// Hamilton product - applies `rhs` first, then `self`
+  fn mul(self, rhs: DQuat) -> DQuat {
+    DQuat::from_xyzw(
+      self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+      self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+      self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+      self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+    )
+  }
+}
+
+impl std::ops::Mul<DVec3> for DQuat {
+  type Output = DVec3;
+  fn mul(self, rhs: DVec3) -> DVec3 {
+    self.rotate(rhs)
+  }
+}
+
+impl std::ops::Neg for DQuat {
+  type Output = DQuat;
+  fn neg(self) -> DQuat {
+    DQuat::from_xyzw(-self.x, -self.y, -self.z, -self.w)
+  }
+}
+
+/// A reference frame placed and oriented inside its parent frame,
+/// e.g. a node relative to its parent node or an entity relative to its node
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frame {
+  pub position: DVec3,
+  pub rotation: DQuat,
+}
+
+impl Frame {
+  pub const IDENTITY: Frame = Frame { position: DVec3::ZERO, rotation: DQuat::IDENTITY };
+
+  pub fn new(position: DVec3, rotation: DQuat) -> Self {
+    Self { position, rotation }
+  }
+
+  /// Point in this frame -> the same point in the parent frame
+  pub fn point_to_parent(&self, point: DVec3) -> DVec3 {
+    self.position + self.rotation * point
+  }
+
+  /// Point in the parent frame -> the same point in this frame
+  pub fn point_from_parent(&self, point: DVec3) -> DVec3 {
+    self.rotation.inverse() * (point - self.position)
+  }
+
+  /// Direction in this frame -> the same direction in the parent frame
+  pub fn vector_to_parent(&self, vector: DVec3) -> DVec3 {
+    self.rotation * vector
+  }
+
+  /// Direction in the parent frame -> the same direction in this frame
+  pub fn vector_from_parent(&self, vector: DVec3) -> DVec3 {
+    self.rotation.inverse() * vector
+  }
+
+  /// Frame of `child` (given relative to this frame) expressed in this frame's parent
+  pub fn compose(&self, child: &Frame) -> Frame {
+    Frame::new(self.point_to_parent(child.position), self.rotation * child.rotation)
+  }
+
+  pub fn inverse(&self) -> Frame {
+    let rotation = self.rotation.inverse();
+    Frame::new(rotation * -self.position, rotation)
+  }
+}
+
+#[cfg(feature = "bevy")]
+mod bevy_conversions {
+  use super::{DQuat, DVec3};
+
+  impl From<DVec3> for bevy_math::DVec3 {
+    fn from(v: DVec3) -> Self {
+      bevy_math::DVec3::new(v.x, v.y, v.z)
+    }
+  }
+
+  impl From<bevy_math::DVec3> for DVec3 {
+    fn from(v: bevy_math::DVec3) -> Self {
+      DVec3::new(v.x, v.y, v.z)
+    }
+  }
+
+  impl From<DQuat> for bevy_math::DQuat {
+    fn from(q: DQuat) -> Self {
+      bevy_math::DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+  }
+
+  impl From<bevy_math::DQuat> for DQuat {
+    fn from(q: bevy_math::DQuat) -> Self {
+      DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+  }
+}
@@ -0,0 +1,98 @@
+//! Manual flight, stepped the same way on the server and on the piloting
+//! client. The client flies its inputs straight away, keeps them until the
+//! server acknowledges them, and replays the rest on top of every
+//! authoritative state it receives.
+
+use std::collections::VecDeque;
+
+use crate::math::DVec3;
+use crate::trajectory::{FlightMode, FlightState, Integrator};
+
+/// Thrust requested for one solver step, in the body frame
+/// (forward = x, up = y, right = z)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PilotInput {
+  pub sequence: u32,
+  pub thrust: DVec3,
+}
+
+/// Everything besides the state and the input that a manual step depends on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShipParameters {
+  pub integrator: Integrator,
+  pub gravitational_parameter: f64,
+  pub mass: f64,
+  pub max_thrust: DVec3,
+}
+
+/// Advance a manually flown ship by one solver step
+pub fn step(state: &mut FlightState, thrust: DVec3, parameters: &ShipParameters) {
+  state.step(
+    FlightMode::Manual(thrust),
+    None,
+    parameters.integrator,
+    parameters.gravitational_parameter,
+    parameters.mass,
+    parameters.max_thrust,
+  );
+}
+
+/// Inputs sent to the server and not acknowledged yet, oldest first
+#[derive(Clone, Debug)]
+pub struct InputBuffer {
+  inputs: VecDeque<PilotInput>,
+  next_sequence: u32,
+}
+
+impl Default for InputBuffer {
+  fn default() -> Self {
+    // Sequence 0 means "nothing acknowledged yet"
+    Self::resume(0)
+  }
+}
+
+impl InputBuffer {
+  /// Continue numbering after an input the server already applied, e.g.
+  /// when picking a ship back up after a reconnect
+  pub fn resume(acknowledged: u32) -> Self {
+    Self { inputs: VecDeque::new(), next_sequence: acknowledged + 1 }
+  }
+
+  /// Number the input for the next step and keep it for replays
+  pub fn record(&mut self, thrust: DVec3) -> PilotInput {
+    let input = PilotInput { sequence: self.next_sequence, thrust };
+    self.next_sequence += 1;
+    self.inputs.push_back(input);
+    input
+  }
+
+  /// Forget every input up to and including the acknowledged one
+  pub fn acknowledge(&mut self, sequence: u32) {
+    while self.inputs.front().is_some_and(|input| input.sequence <= sequence) {
+      self.inputs.pop_front();
+    }
+  }
+
+  pub fn pending(&self) -> impl Iterator<Item = &PilotInput> {
+    self.inputs.iter()
+  }
+
+  pub fn len(&self) -> usize {
+    self.inputs.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.inputs.is_empty()
+  }
+
+  /// The predicted state: the server's state after `acknowledged`, with
+  /// every later input replayed on top
+  pub fn reconcile(&mut self, acknowledged: u32, server_state: FlightState, parameters: &ShipParameters) -> FlightState {
+    self.acknowledge(acknowledged);
+    let mut state = server_state;
+    for input in &self.inputs {
+      step(&mut state, input.thrust, parameters);
+    }
+    state
+  }
+}
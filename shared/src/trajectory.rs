@@ -0,0 +1,736 @@
+// Solvers take the full ship state as separate values, like the server tick hands it over
+#![allow(clippy::too_many_arguments)]
+
+pub use crate::math::DVec3;
+use crate::math::DQuat;
+
+/// Solver step length - one server tick
+pub const DELTA_TIME: f64 = 1.0 / 20.0; // Increased from 1/60 for more responsive simulation
+
+/// Moment of inertia the solver assumes for every ship
+pub const MOMENT_OF_INERTIA: f64 = 10.0; // Much lower for faster rotation
+
+/// Stability assist - torque per unit of spin that the controller spends killing rotation
+pub const ANGULAR_RATE_DAMPING: f64 = 1.0; // 1/s, roughly what the old 5% per tick damping did
+
+/// Newton's gravitational constant (m³⋅kg⁻¹⋅s⁻²)
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-11;
+
+/// Flight control modes that determine how the autopilot behaves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlightMode {
+  /// Maintain current position and orientation (station keeping)
+  Hold,
+  /// Fly to a specific target position
+  NavigateTo(DVec3),
+  /// Follow a continuous target (e.g., player input)
+  Track(DVec3),
+  /// Intercept a moving target at a future point
+  Intercept { target_pos: DVec3, target_vel: DVec3 },
+  /// Manual control - apply thrust directly, in the body frame (forward = x,
+  /// up = y, right = z) and clamped to what each thruster group can give
+  Manual(DVec3), // Direct thrust vector
+  /// Hold a slot relative to the leader handed to the solver (offset is in
+  /// the leader's body frame)
+  Formation { offset: DVec3 },
+}
+
+/// Snapshot of a formation leader, resolved by the caller before solving
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LeaderState {
+  pub position: DVec3,
+  pub velocity: DVec3,
+  pub rotation: DVec3,
+}
+
+/// Ship state expressed in the solver's terms. Rows store a quaternion facing
+/// Bevy's forward (-Z) and a body-frame angular velocity, while the solver
+/// works with pitch/yaw/roll angles and their rates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlightState {
+  pub position: DVec3,
+  pub velocity: DVec3,
+  pub rotation: DVec3, // pitch (x), yaw (y), roll (z)
+  pub rotational_velocity: DVec3, // rates of the angles above
+}
+
+impl FlightState {
+  /// `rotational_velocity` is the angular velocity in the body frame, as rows store it
+  pub fn from_pose(position: DVec3, velocity: DVec3, orientation: DQuat, rotational_velocity: DVec3) -> Self {
+    let forward = orientation * DVec3::FORWARD;
+    let pitch = (-forward.y).clamp(-1.0, 1.0).asin();
+    let yaw = forward.z.atan2(forward.x);
+
+    // Roll is how far the ship's up has turned from the level up around forward
+    let (_, level_up, level_right) = body_axes(DVec3::new(pitch, yaw, 0.0));
+    let up = orientation * DVec3::Y;
+    let roll = up.dot(&level_right).atan2(up.dot(&level_up));
+    let rotation = DVec3::new(pitch, yaw, roll);
+
+    Self {
+      position,
+      velocity,
+      rotation,
+      rotational_velocity: angle_rates(rotation, orientation * rotational_velocity),
+    }
+  }
+
+  /// Quaternion facing where the solver angles point, rolled like them
+  pub fn orientation(&self) -> DQuat {
+    let (forward, up, _) = body_axes(self.rotation);
+    DQuat::look_rotation(forward, up)
+  }
+
+  /// Angular velocity in the body frame, the inverse of what `from_pose` reads
+  pub fn angular_velocity(&self) -> DVec3 {
+    let (forward, _, level_right) = body_axes(DVec3::new(self.rotation.x, self.rotation.y, 0.0));
+    let rates = self.rotational_velocity;
+    // Yaw turns about world down, pitch about the level right, roll about forward
+    let world = DVec3::Y * -rates.y + level_right * -rates.x + forward * rates.z;
+    self.orientation().inverse() * world
+  }
+
+  /// Advance this state by one solver step
+  pub fn step(
+    &mut self,
+    flight_mode: FlightMode,
+    leader: Option<LeaderState>,
+    integrator: Integrator,
+    gravitational_parameter: f64,
+    mass: f64,
+    max_thrust: DVec3,
+  ) {
+    solve(
+      flight_mode,
+      leader,
+      integrator,
+      gravitational_parameter,
+      mass,
+      max_thrust,
+      &mut self.position,
+      &mut self.velocity,
+      &mut self.rotation,
+      &mut self.rotational_velocity,
+    );
+  }
+
+  pub fn as_leader(&self) -> LeaderState {
+    LeaderState {
+      position: self.position,
+      velocity: self.velocity,
+      rotation: self.rotation,
+    }
+  }
+}
+
+/// Acceleration toward a point mass at the origin of the current frame
+pub fn point_mass_gravity(gravitational_parameter: f64, position: DVec3) -> DVec3 {
+  let distance = position.length();
+  if gravitational_parameter <= 0.0 || distance == 0.0 {
+    return DVec3::zero();
+  }
+  
+  // a = -μ r / |r|³
+  position * (-gravitational_parameter / (distance * distance * distance))
+}
+
+/// Numerical scheme used to advance positions and velocities
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Integrator {
+  /// First order and symplectic - cheap, keeps orbits bounded
+  SemiImplicitEuler,
+  /// Second order and symplectic - conserves energy well at the same cost per step
+  #[default]
+  VelocityVerlet,
+  /// Classic fourth order Runge-Kutta - most accurate per step, slowly drifts in energy
+  RungeKutta4,
+}
+
+/// Advance position and velocity under constant thrust plus gravity from the frame's body
+pub fn integrate_translation(
+  integrator: Integrator,
+  thrust_acceleration: DVec3,
+  gravitational_parameter: f64,
+  position: &mut DVec3,
+  velocity: &mut DVec3,
+  delta_time: f64,
+) {
+  let acceleration_at = |p: DVec3| thrust_acceleration + point_mass_gravity(gravitational_parameter, p);
+  
+  match integrator {
+    Integrator::SemiImplicitEuler => {
+      *velocity += acceleration_at(*position) * delta_time;
+      *position += *velocity * delta_time;
+    },
+    
+    Integrator::VelocityVerlet => {
+      let a0 = acceleration_at(*position);
+      *position += *velocity * delta_time + a0 * (0.5 * delta_time * delta_time);
+      let a1 = acceleration_at(*position);
+      *velocity += (a0 + a1) * (0.5 * delta_time);
+    },
+    
+    Integrator::RungeKutta4 => {
+      let half = delta_time * 0.5;
+      let (x0, v0) = (*position, *velocity);
+      
+      let k1x = v0;
+      let k1v = acceleration_at(x0);
+      let k2x = v0 + k1v * half;
+      let k2v = acceleration_at(x0 + k1x * half);
+      let k3x = v0 + k2v * half;
+      let k3v = acceleration_at(x0 + k2x * half);
+      let k4x = v0 + k3v * delta_time;
+      let k4v = acceleration_at(x0 + k3x * delta_time);
+      
+      *position = x0 + (k1x + k2x * 2.0 + k3x * 2.0 + k4x) * (delta_time / 6.0);
+      *velocity = v0 + (k1v + k2v * 2.0 + k3v * 2.0 + k4v) * (delta_time / 6.0);
+    },
+  }
+}
+
+/// Core Newtonian physics simulation - no control logic and no damping, just pure physics
+pub fn simulate_physics(
+  integrator: Integrator,
+  thrust_vector: DVec3,
+  torque_vector: DVec3,
+  gravitational_parameter: f64, // μ = GM of the frame's body, 0 for no gravity
+  mass: f64,
+  moment_of_inertia: f64,
+  position: &mut DVec3,
+  velocity: &mut DVec3,
+  rotation: &mut DVec3,
+  rotational_velocity: &mut DVec3,
+  delta_time: f64,
+) {
+  // Apply thrust to create acceleration (F = ma, so a = F/m)
+  let thrust_acceleration = thrust_vector * (1.0 / mass);
+  
+  integrate_translation(integrator, thrust_acceleration, gravitational_parameter, position, velocity, delta_time);
+  
+  // Apply torque to create angular acceleration (τ = Iα, so α = τ/I)
+  let angular_acceleration = torque_vector * (1.0 / moment_of_inertia);
+  
+  // Torque is constant over the step, so only the first order scheme differs here
+  let previous_rotational_velocity = *rotational_velocity;
+  *rotational_velocity += angular_acceleration * delta_time;
+  
+  match integrator {
+    Integrator::SemiImplicitEuler => {
+      *rotation += *rotational_velocity * delta_time;
+    },
+    Integrator::VelocityVerlet | Integrator::RungeKutta4 => {
+      *rotation += (previous_rotational_velocity + *rotational_velocity) * (0.5 * delta_time);
+    },
+  }
+  
+  // Normalize rotation angles
+  rotation.x %= 2.0 * std::f64::consts::PI;
+  rotation.y %= 2.0 * std::f64::consts::PI;
+  rotation.z %= 2.0 * std::f64::consts::PI;
+}
+
+/// Flight control system - calculates thrust needed to achieve desired behavior.
+/// Thrust comes back in the body frame (forward = x, up = y, right = z).
+pub fn calculate_flight_control(
+  flight_mode: FlightMode,
+  current_position: DVec3,
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  current_rotational_velocity: DVec3,
+  mass: f64,
+  max_main_thrust: f64,    // Forward thrust (X)
+  max_retro_thrust: f64,   // Reverse thrust (Y) 
+  max_nav_thrust: f64,     // Maneuvering thrust (Z)
+  leader: Option<LeaderState>, // Required by Formation, ignored by every other mode
+  _delta_time: f64,
+) -> (DVec3, DVec3) { // Returns (thrust_vector, torque_vector)
+  let (thrust, torque) = match flight_mode {
+    FlightMode::Hold => {
+      // Station keeping - gentle corrections to maintain position
+      calculate_hold_control(current_velocity, current_rotation, mass, max_nav_thrust)
+    },
+    
+    FlightMode::NavigateTo(target_pos) => {
+      calculate_navigation_control(
+        current_position,
+        current_velocity,
+        current_rotation,
+        target_pos,
+        mass,
+        max_main_thrust,
+        max_retro_thrust,
+        max_nav_thrust,
+        _delta_time,
+      )
+    },
+    
+    FlightMode::Track(target_pos) => {
+      // Similar to NavigateTo but with different tuning for responsive tracking
+      calculate_tracking_control(
+        current_position,
+        current_velocity,
+        current_rotation,
+        target_pos,
+        mass,
+        max_main_thrust,
+        max_retro_thrust,
+        max_nav_thrust,
+        _delta_time,
+      )
+    },
+    
+    FlightMode::Intercept { target_pos, target_vel } => {
+      calculate_intercept_control(
+        current_position,
+        current_velocity,
+        current_rotation,
+        target_pos,
+        target_vel,
+        mass,
+        max_main_thrust,
+        max_retro_thrust,
+        max_nav_thrust,
+        _delta_time,
+      )
+    },
+    
+    FlightMode::Manual(thrust_vector) => {
+      // Direct thrust control - convert to body-relative thrust
+      let clamped_thrust = DVec3::new(
+        thrust_vector.x.clamp(-max_retro_thrust, max_main_thrust), // Forward/back
+        thrust_vector.y.clamp(-max_nav_thrust, max_nav_thrust),    // Up/down
+        thrust_vector.z.clamp(-max_nav_thrust, max_nav_thrust),    // Left/right
+      );
+      (clamped_thrust, DVec3::zero()) // No automatic rotation in manual mode
+    },
+    
+    FlightMode::Formation { offset } => match leader {
+      Some(leader) => calculate_formation_control(
+        current_position,
+        current_velocity,
+        current_rotation,
+        leader,
+        offset,
+        mass,
+        max_main_thrust,
+        max_retro_thrust,
+        max_nav_thrust,
+        _delta_time,
+      ),
+      // Leader is gone - stop where we are rather than chase a stale slot
+      None => calculate_hold_control(current_velocity, current_rotation, mass, max_nav_thrust),
+    },
+  };
+  
+  (thrust, torque + calculate_stability_assist(current_rotational_velocity, max_nav_thrust))
+}
+
+/// Counter-torque against spin. This is the only rotational damping in the simulation.
+fn calculate_stability_assist(current_rotational_velocity: DVec3, max_nav_thrust: f64) -> DVec3 {
+  let damping = current_rotational_velocity * (-ANGULAR_RATE_DAMPING * MOMENT_OF_INERTIA);
+  let max_torque = max_nav_thrust * 3.0;
+  
+  DVec3::new(
+    damping.x.clamp(-max_torque, max_torque),
+    damping.y.clamp(-max_torque, max_torque),
+    damping.z.clamp(-max_torque, max_torque),
+  )
+}
+
+/// Unit vector the ship is facing for a given rotation (pitch = x, yaw = y)
+pub fn forward_direction(rotation: DVec3) -> DVec3 {
+  DVec3::new(
+    rotation.y.cos() * rotation.x.cos(),
+    -rotation.x.sin(),
+    rotation.y.sin() * rotation.x.cos(),
+  )
+}
+
+/// Forward, up and right of a ship at the given pitch/yaw/roll. Pitch turns
+/// the nose about a right axis kept level, so the axes are defined straight
+/// up and down too.
+fn body_axes(rotation: DVec3) -> (DVec3, DVec3, DVec3) {
+  let forward = forward_direction(rotation);
+  let level_right = DVec3::new(-rotation.y.sin(), 0.0, rotation.y.cos());
+  let level_up = level_right.cross(&forward);
+  let (sin_roll, cos_roll) = rotation.z.sin_cos();
+  let up = level_up * cos_roll + level_right * sin_roll;
+  let right = level_right * cos_roll - level_up * sin_roll;
+  (forward, up, right)
+}
+
+/// Pitch/yaw/roll rates for a world-frame angular velocity at the given angles
+fn angle_rates(rotation: DVec3, angular_velocity: DVec3) -> DVec3 {
+  let (forward, level_up, level_right) = body_axes(DVec3::new(rotation.x, rotation.y, 0.0));
+  let (sin_pitch, cos_pitch) = rotation.x.sin_cos();
+  let pitch_rate = -angular_velocity.dot(&level_right);
+  // Straight up or down yaw and roll turn about the same axis - call it roll
+  let yaw_rate = if cos_pitch.abs() > 1e-9 {
+    -angular_velocity.dot(&level_up) / cos_pitch
+  } else {
+    0.0
+  };
+  let roll_rate = angular_velocity.dot(&forward) - yaw_rate * sin_pitch;
+  DVec3::new(pitch_rate, yaw_rate, roll_rate)
+}
+
+/// Convert a body-frame vector (forward = x, up = y, right = z) into world space
+pub fn body_to_world(rotation: DVec3, body_vector: DVec3) -> DVec3 {
+  let (forward, up, right) = body_axes(rotation);
+  forward * body_vector.x + up * body_vector.y + right * body_vector.z
+}
+
+/// Inverse of `body_to_world`
+pub fn world_to_body(rotation: DVec3, world_vector: DVec3) -> DVec3 {
+  let (forward, up, right) = body_axes(rotation);
+  DVec3::new(world_vector.dot(&forward), world_vector.dot(&up), world_vector.dot(&right))
+}
+
+/// Calculate attitude control - determines rotation needed to face target direction
+fn calculate_attitude_control(
+  current_rotation: DVec3,
+  target_direction: DVec3,
+  max_nav_thrust: f64,
+) -> DVec3 {
+  // Simplified attitude control - just point toward target
+  let desired_yaw = target_direction.z.atan2(target_direction.x);
+  let desired_pitch = (-target_direction.y).atan2((target_direction.x.powi(2) + target_direction.z.powi(2)).sqrt());
+  
+  // Calculate rotation errors
+  let mut yaw_error = desired_yaw - current_rotation.y;
+  let mut pitch_error = desired_pitch - current_rotation.x;
+  
+  // Normalize angles to -π to π for shortest rotation path
+  if yaw_error > std::f64::consts::PI {
+    yaw_error -= 2.0 * std::f64::consts::PI;
+  } else if yaw_error < -std::f64::consts::PI {
+    yaw_error += 2.0 * std::f64::consts::PI;
+  }
+  
+  if pitch_error > std::f64::consts::PI {
+    pitch_error -= 2.0 * std::f64::consts::PI;
+  } else if pitch_error < -std::f64::consts::PI {
+    pitch_error += 2.0 * std::f64::consts::PI;
+  }
+  
+  // Strong attitude control gains for responsive rotation
+  let attitude_gain = 25.0; // Even stronger rotation for better alignment
+  let max_torque = max_nav_thrust * 3.0; // More torque available for faster rotation
+  
+  DVec3::new(
+    (pitch_error * attitude_gain).clamp(-max_torque, max_torque),
+    (yaw_error * attitude_gain).clamp(-max_torque, max_torque),
+    0.0, // No roll control for now
+  )
+}
+
+/// Station keeping - maintain current position by countering drift
+fn calculate_hold_control(
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  mass: f64,
+  max_nav_thrust: f64,
+) -> (DVec3, DVec3) {
+  // Apply gentle counter-thrust to stop drift using nav thrusters only
+  let damping_factor = 0.1; // Fraction of the drift cancelled per second
+  let counter_thrust = world_to_body(current_rotation, current_velocity * (-damping_factor * mass));
+  let hold_thrust = DVec3::new(
+    counter_thrust.x.clamp(-max_nav_thrust, max_nav_thrust),
+    counter_thrust.y.clamp(-max_nav_thrust, max_nav_thrust),
+    counter_thrust.z.clamp(-max_nav_thrust, max_nav_thrust),
+  );
+  
+  // Minimal attitude control to stay stable
+  let torque = DVec3::new(
+    (-current_rotation.x * 0.1).clamp(-max_nav_thrust * 0.05, max_nav_thrust * 0.05),
+    (-current_rotation.y * 0.1).clamp(-max_nav_thrust * 0.05, max_nav_thrust * 0.05),
+    0.0,
+  );
+  
+  (hold_thrust, torque)
+}
+
+/// Calculate thrust for precise navigation to a fixed target
+fn calculate_navigation_control(
+  current_position: DVec3,
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  target_position: DVec3,
+  mass: f64,
+  max_main_thrust: f64,
+  max_retro_thrust: f64,
+  max_nav_thrust: f64,
+  _delta_time: f64,
+) -> (DVec3, DVec3) {
+  let displacement = target_position - current_position;
+  let distance = displacement.length();
+  let response_time = 1.0 / 6.0; // Seconds to cancel a velocity error
+  
+  let arrival_radius = 1.0; // Close enough to stop turning and just trim into place
+  if distance < arrival_radius {
+    // Settle onto the target with the nav thrusters, keeping the current heading
+    let desired_velocity = displacement * 0.5;
+    let correction = world_to_body(current_rotation, (desired_velocity - current_velocity) * (mass / response_time));
+    let thrust = DVec3::new(
+      correction.x.clamp(-max_nav_thrust, max_nav_thrust),
+      correction.y.clamp(-max_nav_thrust, max_nav_thrust),
+      correction.z.clamp(-max_nav_thrust, max_nav_thrust),
+    );
+    return (thrust, DVec3::zero());
+  }
+  
+  let target_direction = displacement.normalize();
+  
+  // Calculate attitude control first
+  let torque = calculate_attitude_control(current_rotation, target_direction, max_nav_thrust);
+  
+  // Check if ship is pointing roughly in the right direction
+  let ship_forward = forward_direction(current_rotation);
+  
+  let alignment = ship_forward.dot(&target_direction);
+  let alignment_threshold = 0.7; // Much stricter alignment requirement - ships must be well-aligned
+  
+  let approach_speed = if distance > 15.0 {
+    25.0 // Moderate speed for long distances
+  } else {
+    (distance * 2.0).max(1.0) // Slower approach for precision
+  };
+  
+  // Never go faster than the retro thrusters can stop us from in the remaining distance
+  let braking_speed = (2.0 * (max_retro_thrust / mass) * distance).sqrt() * 0.8;
+  
+  let desired_velocity = target_direction * approach_speed.min(braking_speed);
+  let correction = world_to_body(current_rotation, (desired_velocity - current_velocity) * (mass / response_time));
+  
+  let main_thrust = if alignment > alignment_threshold {
+    // Ship is well aligned - main thrust to accelerate toward target, retro thrust to brake
+    correction.x.clamp(-max_retro_thrust, max_main_thrust)
+  } else {
+    // Ship not aligned - NO main thrust, just rotate to face target
+    0.0
+  };
+  
+  // Nav thrusters trim sideways drift so we don't slide past the target
+  let thrust = DVec3::new(
+    main_thrust,
+    correction.y.clamp(-max_nav_thrust, max_nav_thrust),
+    correction.z.clamp(-max_nav_thrust, max_nav_thrust),
+  );
+  
+  (thrust, torque)
+}
+
+/// Calculate thrust for responsive tracking of a moving target
+fn calculate_tracking_control(
+  current_position: DVec3,
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  target_position: DVec3,
+  _mass: f64,
+  max_main_thrust: f64,
+  _max_retro_thrust: f64,
+  max_nav_thrust: f64,
+  _delta_time: f64,
+) -> (DVec3, DVec3) {
+  // Similar to navigation but more aggressive
+  calculate_navigation_control(
+    current_position,
+    current_velocity,
+    current_rotation,
+    target_position,
+    _mass,
+    max_main_thrust * 1.2, // More aggressive thrust
+    _max_retro_thrust,
+    max_nav_thrust,
+    _delta_time,
+  )
+}
+
+/// Calculate thrust for intercepting a moving target
+fn calculate_intercept_control(
+  current_position: DVec3,
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  target_position: DVec3,
+  target_velocity: DVec3,
+  mass: f64,
+  max_main_thrust: f64,
+  max_retro_thrust: f64,
+  max_nav_thrust: f64,
+  delta_time: f64,
+) -> (DVec3, DVec3) {
+  // Predict where the target will be
+  let time_to_intercept = estimate_intercept_time(
+    current_position,
+    current_velocity,
+    target_position,
+    target_velocity,
+  );
+  
+  let predicted_target_position = target_position + target_velocity * time_to_intercept;
+  
+  // Navigate to the predicted position
+  calculate_navigation_control(
+    current_position,
+    current_velocity,
+    current_rotation,
+    predicted_target_position,
+    mass,
+    max_main_thrust,
+    max_retro_thrust,
+    max_nav_thrust,
+    delta_time,
+  )
+}
+
+/// Calculate thrust for holding a slot in a leader's formation
+fn calculate_formation_control(
+  current_position: DVec3,
+  current_velocity: DVec3,
+  current_rotation: DVec3,
+  leader: LeaderState,
+  offset: DVec3,
+  mass: f64,
+  max_main_thrust: f64,
+  max_retro_thrust: f64,
+  max_nav_thrust: f64,
+  delta_time: f64,
+) -> (DVec3, DVec3) {
+  let slot_position = leader.position + body_to_world(leader.rotation, offset);
+  let displacement = slot_position - current_position;
+  let distance = displacement.length();
+  
+  let join_distance = 10.0; // Beyond this the slot is just another moving target
+  if distance > join_distance {
+    return calculate_intercept_control(
+      current_position,
+      current_velocity,
+      current_rotation,
+      slot_position,
+      leader.velocity,
+      mass,
+      max_main_thrust,
+      max_retro_thrust,
+      max_nav_thrust,
+      delta_time,
+    );
+  }
+  
+  // In the slot - match the leader's velocity, plus a gentle pull back toward the slot
+  let position_gain = 0.5;
+  let response_time = 1.0; // Seconds to cancel a velocity error
+  let desired_velocity = leader.velocity + displacement * position_gain;
+  let velocity_error = desired_velocity - current_velocity;
+  let correction = world_to_body(current_rotation, velocity_error * (mass / response_time));
+  
+  // Station keeping uses the nav thrusters only
+  let thrust = DVec3::new(
+    correction.x.clamp(-max_nav_thrust, max_nav_thrust),
+    correction.y.clamp(-max_nav_thrust, max_nav_thrust),
+    correction.z.clamp(-max_nav_thrust, max_nav_thrust),
+  );
+  
+  // Face the same way as the leader
+  let torque = calculate_attitude_control(current_rotation, forward_direction(leader.rotation), max_nav_thrust);
+  
+  (thrust, torque)
+}
+
+/// Estimate time required to intercept a moving target
+fn estimate_intercept_time(
+  current_position: DVec3,
+  current_velocity: DVec3,
+  target_position: DVec3,
+  target_velocity: DVec3,
+) -> f64 {
+  let relative_position = target_position - current_position;
+  let relative_velocity = target_velocity - current_velocity;
+  
+  let distance = relative_position.length();
+  let closing_speed = relative_velocity.length();
+  
+  if closing_speed < 0.1 {
+    // Not closing significantly - use simple time estimate
+    distance / 10.0 // Assume average approach speed
+  } else {
+    distance / closing_speed
+  }
+}
+
+/// Main trajectory solver - combines flight control with physics simulation.
+/// Returns the body-frame thrust and torque that were applied.
+pub fn solve(
+  flight_mode: FlightMode,
+  leader: Option<LeaderState>,
+  integrator: Integrator,
+  gravitational_parameter: f64, // μ = GM of the frame's body, 0 for no gravity
+  mass: f64,
+  max_thrust: DVec3, // x=main, y=retro, z=nav
+  position: &mut DVec3,
+  velocity: &mut DVec3,
+  rotation: &mut DVec3,
+  rotational_velocity: &mut DVec3,
+) -> (DVec3, DVec3) {
+  // Calculate flight control commands (thrust and torque)
+  let (thrust_vector, torque_vector) = calculate_flight_control(
+    flight_mode,
+    *position,
+    *velocity,
+    *rotation,
+    *rotational_velocity,
+    mass,
+    max_thrust.x, // Main thrust
+    max_thrust.y, // Retro thrust  
+    max_thrust.z, // Nav thrust
+    leader,
+    DELTA_TIME,
+  );
+  
+  // Thrusters are fixed to the hull - point the thrust where the ship is facing
+  let world_thrust = body_to_world(*rotation, thrust_vector);
+  
+  // Apply physics simulation
+  simulate_physics(
+    integrator,
+    world_thrust,
+    torque_vector,
+    gravitational_parameter,
+    mass,
+    MOMENT_OF_INERTIA,
+    position,
+    velocity,
+    rotation,
+    rotational_velocity,
+    DELTA_TIME,
+  );
+  
+  (thrust_vector, torque_vector)
+}
+
+/// Legacy function for backward compatibility - converts target position to NavigateTo mode
+#[allow(dead_code)]
+pub fn solve_to_target(
+  target_position: DVec3,
+  mass: f64,
+  max_thrust: DVec3,
+  position: &mut DVec3,
+  velocity: &mut DVec3,
+  rotation: &mut DVec3,
+  rotational_velocity: &mut DVec3,
+) {
+  solve(
+    FlightMode::NavigateTo(target_position),
+    None,
+    Integrator::default(),
+    0.0,
+    mass,
+    max_thrust,
+    position,
+    velocity,
+    rotation,
+    rotational_velocity,
+  );
+}
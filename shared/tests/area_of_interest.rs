@@ -0,0 +1,20 @@
+//! Cell coordinates used for area-of-interest subscriptions.
+
+use shared::area_of_interest::{Cell, CELL_SIZE};
+use shared::math::DVec3;
+
+#[test]
+fn cells_floor_towards_negative_infinity() {
+  assert_eq!(Cell::containing(DVec3::new(0.0, 999.0, -1.0)), Cell { x: 0, y: 0, z: -1 });
+  assert_eq!(Cell::containing(DVec3::new(-CELL_SIZE, CELL_SIZE, -1.5 * CELL_SIZE)), Cell { x: -1, y: 1, z: -2 });
+}
+
+#[test]
+fn span_covers_the_radius() {
+  assert_eq!(Cell::span(CELL_SIZE), 1);
+  assert_eq!(Cell::span(2.5 * CELL_SIZE), 3);
+
+  let centre = Cell::containing(DVec3::ZERO);
+  let edge = Cell::containing(DVec3::new(2.5 * CELL_SIZE, -2.5 * CELL_SIZE, 0.0));
+  assert!(centre.distance(&edge) <= Cell::span(2.5 * CELL_SIZE));
+}
@@ -0,0 +1,144 @@
+//! The waypoint autopilot the server tick flies ships with.
+
+use shared::autopilot::{self, AutopilotState};
+use shared::math::{DQuat, DVec3};
+
+const MASS: f64 = 1000.0;
+const MAX_IMPULSE: DVec3 = DVec3 { x: 400.0, y: 250.0, z: 150.0 };
+/// An 8 x 6 x 10 m hull, see `moment_of_inertia_averages_the_three_box_axes`
+const MOMENT_OF_INERTIA: f64 = MASS * 400.0 / 36.0;
+/// Well past the time a 90° turn and a 50 m trip take
+const MAX_STEPS: usize = 20 * 120;
+
+fn at_rest() -> AutopilotState {
+  AutopilotState {
+    position: DVec3::ZERO,
+    velocity: DVec3::ZERO,
+    rotation: DQuat::IDENTITY,
+    rotational_velocity: DVec3::ZERO,
+  }
+}
+
+/// Ahead is -Z, so this waypoint needs a quarter turn first
+const ABEAM: DVec3 = DVec3 { x: 50.0, y: 0.0, z: 0.0 };
+
+/// Steps until the autopilot reports arrival, handing every state to `check`
+fn fly_to(state: &mut AutopilotState, target: DVec3, mut check: impl FnMut(&AutopilotState)) -> usize {
+  for step in 1..=MAX_STEPS {
+    if autopilot::step(state, target, MASS, MOMENT_OF_INERTIA, MAX_IMPULSE) {
+      return step;
+    }
+    check(state);
+  }
+  panic!("never reached {target:?}, stopped at {:?}", state.position);
+}
+
+fn heading_error(state: &AutopilotState, target: DVec3) -> f64 {
+  let direction = (target - state.position).normalize();
+  state.rotation.angle_between(&DQuat::from_rotation_arc(DVec3::FORWARD, direction))
+}
+
+#[test]
+fn moment_of_inertia_averages_the_three_box_axes() {
+  // (8² + 6²) + (10² + 6²) + (10² + 8²) = 400
+  let moment = autopilot::box_moment_of_inertia(MASS, autopilot::DEFAULT_SHIP_DIMENSIONS);
+  assert!((moment - MOMENT_OF_INERTIA).abs() < 1e-9);
+}
+
+#[test]
+fn turns_toward_the_waypoint_before_moving() {
+  let mut state = at_rest();
+  fly_to(&mut state, ABEAM, |state| {
+    if state.velocity.length() > 0.0 {
+      // It only started pushing once nearly lined up
+      assert!(heading_error(state, ABEAM) < autopilot::ORIENTATION_TOLERANCE);
+    } else {
+      assert_eq!(state.position, DVec3::ZERO);
+    }
+  });
+}
+
+#[test]
+fn turn_rate_stays_within_what_the_thrusters_can_stop() {
+  let max_acceleration = MAX_IMPULSE.z / MOMENT_OF_INERTIA;
+  let mut state = at_rest();
+  let mut previous = 0.0;
+  fly_to(&mut state, ABEAM, |state| {
+    let rate = state.rotational_velocity.length();
+    // Never spins up faster than the nav thrusters allow
+    assert!(rate - previous <= max_acceleration * shared::trajectory::DELTA_TIME + 1e-12);
+    // Never faster than it could still stop from within a quarter turn
+    assert!(rate <= (max_acceleration * std::f64::consts::FRAC_PI_2).sqrt() + 1e-12);
+    previous = rate;
+  });
+}
+
+#[test]
+fn cruises_no_faster_than_the_speed_cap() {
+  let mut state = at_rest();
+  let mut top_speed: f64 = 0.0;
+  fly_to(&mut state, ABEAM, |state| top_speed = top_speed.max(state.velocity.length()));
+  // The gentle hold-speed thrust may nudge it just past the cap
+  assert!(top_speed <= autopilot::MAX_CRUISE_SPEED + 0.1, "top speed {top_speed}");
+  assert!(top_speed > autopilot::MAX_CRUISE_SPEED - 0.1, "top speed {top_speed}");
+}
+
+#[test]
+fn brakes_on_the_approach() {
+  let mut state = at_rest();
+  let mut previous = f64::MAX;
+  fly_to(&mut state, ABEAM, |state| {
+    if state.position.distance(&ABEAM) < autopilot::APPROACH_DISTANCE {
+      let speed = state.velocity.length();
+      assert!(speed <= previous, "sped up to {speed} on the approach");
+      previous = speed;
+    }
+  });
+  // Slowed to approach speed before it got there
+  assert!(previous < autopilot::MAX_APPROACH_SPEED, "arrived at {previous}");
+}
+
+#[test]
+fn arrives_stopped_and_facing_the_waypoint() {
+  let mut state = at_rest();
+  fly_to(&mut state, ABEAM, |_| {});
+
+  assert!(state.position.distance(&ABEAM) < autopilot::ARRIVAL_TOLERANCE);
+  assert_eq!(state.velocity, DVec3::ZERO);
+  let facing = state.rotation.rotate(DVec3::FORWARD);
+  assert!(facing.dot(&DVec3::X) > autopilot::ORIENTATION_TOLERANCE.cos());
+}
+
+#[test]
+fn a_waypoint_dead_ahead_needs_no_turn() {
+  let ahead = DVec3::new(0.0, 0.0, -30.0);
+  let mut state = at_rest();
+  autopilot::step(&mut state, ahead, MASS, MOMENT_OF_INERTIA, MAX_IMPULSE);
+
+  assert!(state.velocity.dot(&DVec3::FORWARD) > 0.0);
+  assert_eq!(state.rotational_velocity, DVec3::ZERO);
+}
+
+#[test]
+fn a_smaller_hull_turns_onto_the_waypoint_sooner() {
+  let turn_steps = |moment_of_inertia: f64| {
+    let mut state = at_rest();
+    let mut steps = 0;
+    while state.velocity.length() == 0.0 {
+      autopilot::step(&mut state, ABEAM, MASS, moment_of_inertia, MAX_IMPULSE);
+      steps += 1;
+    }
+    steps
+  };
+  let small_hull = autopilot::box_moment_of_inertia(MASS, DVec3::new(1.0, 1.0, 3.0));
+  assert!(turn_steps(small_hull) < turn_steps(MOMENT_OF_INERTIA));
+}
+
+#[test]
+fn a_waypoint_on_top_of_the_ship_counts_as_reached() {
+  let mut state = at_rest();
+  state.velocity = DVec3::new(0.05, 0.0, 0.0);
+  let start = state.position;
+  assert!(autopilot::step(&mut state, start, MASS, MOMENT_OF_INERTIA, MAX_IMPULSE));
+  assert_eq!(state.velocity, DVec3::ZERO);
+}
@@ -0,0 +1,75 @@
+//! Sanity checks for the embedded solar-system catalog.
+
+use shared::catalog::{self, ASTRONOMICAL_UNIT};
+use shared::math::DVec3;
+use shared::NodeName;
+
+const DAY: f64 = 86_400.0;
+
+fn state_of(name: NodeName) -> (DVec3, DVec3) {
+  let body = catalog::find(&name).unwrap();
+  let parent = catalog::find(body.parent.as_ref().unwrap()).unwrap();
+  body.orbit.unwrap().state_vector(parent.gravitational_parameter())
+}
+
+#[test]
+fn parents_come_before_their_children() {
+  let bodies = catalog::bodies();
+  for (index, body) in bodies.iter().enumerate() {
+    assert_eq!(
+      bodies.iter().filter(|other| other.name == body.name).count(),
+      1,
+      "{} is listed more than once",
+      body.name
+    );
+    assert_eq!(body.parent.is_some(), body.orbit.is_some(), "{} needs both a parent and an orbit", body.name);
+    if let Some(parent) = &body.parent {
+      assert!(
+        bodies[..index].iter().any(|other| other.name == *parent),
+        "{} is listed before its parent {}",
+        body.name,
+        parent
+      );
+    }
+  }
+  assert!(catalog::find(&NodeName::Custom("Sandbox".to_string())).is_none());
+}
+
+#[test]
+fn earth_orbits_at_one_au() {
+  let (position, velocity) = state_of(NodeName::Earth);
+  let distance = position.length() / ASTRONOMICAL_UNIT;
+  let speed = velocity.length();
+  assert!((0.98..1.02).contains(&distance), "Earth at {} AU", distance);
+  assert!((29_000.0..30_500.0).contains(&speed), "Earth moving at {} m/s", speed);
+
+  // Orbits the right way round: counter-clockwise seen from ecliptic north (+Y)
+  assert!(position.cross(&velocity).y > 0.0);
+
+  let sun = catalog::find(&NodeName::Sun).unwrap();
+  let period = catalog::find(&NodeName::Earth).unwrap().orbit.unwrap().period(sun.gravitational_parameter());
+  assert!((period / DAY - 365.25).abs() < 0.5, "Earth year is {} days", period / DAY);
+}
+
+#[test]
+fn moons_orbit_their_planets() {
+  let (position, velocity) = state_of(NodeName::Moon);
+  assert!((356_000e3..407_000e3).contains(&position.length()));
+  assert!((950.0..1_100.0).contains(&velocity.length()));
+
+  // Triton goes the other way round
+  let (position, velocity) = state_of(NodeName::Triton);
+  assert!(position.cross(&velocity).y < 0.0);
+}
+
+#[test]
+fn state_vector_conserves_orbital_energy() {
+  // Vis-viva: v² = μ(2/r - 1/a) holds at any point of the orbit
+  let sun = catalog::find(&NodeName::Sun).unwrap();
+  for name in [NodeName::Mercury, NodeName::Pluto, NodeName::Eris] {
+    let orbit = catalog::find(&name).unwrap().orbit.unwrap();
+    let (position, velocity) = orbit.state_vector(sun.gravitational_parameter());
+    let expected = sun.gravitational_parameter() * (2.0 / position.length() - 1.0 / orbit.semi_major_axis);
+    assert!((velocity.length_squared() / expected - 1.0).abs() < 1e-9, "{} breaks vis-viva", name);
+  }
+}
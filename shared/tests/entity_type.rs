@@ -0,0 +1,16 @@
+//! Entity kinds round-trip through their names, and nothing else parses.
+
+use shared::EntityType;
+
+#[test]
+fn built_in_kinds_round_trip_through_their_names() {
+  for kind in [EntityType::Star, EntityType::Planet, EntityType::Moon, EntityType::Asteroid, EntityType::Comet, EntityType::Ship] {
+    assert_eq!(kind.to_string().parse::<EntityType>(), Ok(kind));
+  }
+}
+
+#[test]
+fn unknown_names_are_an_error() {
+  assert!("Container".parse::<EntityType>().is_err());
+  assert!("".parse::<EntityType>().is_err());
+}
@@ -0,0 +1,388 @@
+//! Regression suite for the flight controllers in `shared::trajectory`.
+//!
+//! Most tests fly one `FlightMode` from a fixed starting state with the same
+//! solver the server tick uses, then check how long it took to settle, how
+//! far it overshot, where it ended up and how much propellant it burned. The
+//! rest check single control outputs against the gains they come from.
+
+use shared::trajectory::{
+  calculate_flight_control, solve, DVec3, FlightMode, Integrator, LeaderState, DELTA_TIME,
+};
+
+const MASS: f64 = 1000.0; // kg, same as the TestShip
+const MAX_THRUST: DVec3 = DVec3 { x: 400.0, y: 250.0, z: 150.0 }; // main, retro, nav
+const EXHAUST_VELOCITY: f64 = 300.0 * 9.80665; // m/s, 300s specific impulse
+const SETTLED_SPEED: f64 = 0.1; // m/s relative to the goal that counts as settled
+
+struct Case {
+  mode: FlightMode,
+  position: DVec3,
+  velocity: DVec3,
+  rotation: DVec3,
+  /// Leader start state for formation cases - it coasts at constant velocity
+  leader: Option<LeaderState>,
+  duration: f64,
+  /// Distance from the goal that counts as arrived
+  tolerance: f64,
+  max_convergence_time: f64,
+  max_overshoot: f64,
+  max_final_error: f64,
+  /// Speed relative to the goal at the end of the run
+  max_final_speed: f64,
+  max_propellant: f64,
+}
+
+struct Report {
+  convergence_time: Option<f64>,
+  overshoot: f64,
+  final_error: f64,
+  final_speed: f64,
+  propellant: f64,
+}
+
+fn v(x: f64, y: f64, z: f64) -> DVec3 {
+  DVec3::new(x, y, z)
+}
+
+/// Flight mode as the server would hand it to the solver at a given moment -
+/// an intercept target keeps moving, so its position is refreshed every tick
+fn mode_at(case: &Case, elapsed: f64) -> FlightMode {
+  match case.mode {
+    FlightMode::Intercept { target_pos, target_vel } => FlightMode::Intercept {
+      target_pos: target_pos + target_vel * elapsed,
+      target_vel,
+    },
+    mode => mode,
+  }
+}
+
+/// Where the ship should be, and how fast it should be moving, at a given moment
+fn goal(case: &Case, position: DVec3, leader: Option<LeaderState>, elapsed: f64) -> (DVec3, DVec3) {
+  match (mode_at(case, elapsed), leader) {
+    (FlightMode::NavigateTo(target), _) | (FlightMode::Track(target), _) => (target, DVec3::zero()),
+    (FlightMode::Intercept { target_pos, target_vel }, _) => (target_pos, target_vel),
+    (FlightMode::Formation { offset }, Some(leader)) => (
+      leader.position + shared::trajectory::body_to_world(leader.rotation, offset),
+      leader.velocity,
+    ),
+    // Holding only has to stop, wherever that ends up
+    _ => (position, DVec3::zero()),
+  }
+}
+
+fn fly(case: &Case) -> Report {
+  let mut position = case.position;
+  let mut velocity = case.velocity;
+  let mut rotation = case.rotation;
+  let mut rotational_velocity = DVec3::zero();
+  let mut leader = case.leader;
+
+  let (start_goal, _) = goal(case, position, leader, 0.0);
+  let approach = (start_goal - case.position).normalize();
+
+  let mut convergence_time = None;
+  let mut overshoot: f64 = 0.0;
+  let mut impulse = 0.0;
+  let steps = (case.duration / DELTA_TIME).round() as u32;
+
+  for step in 1..=steps {
+    let (thrust, _) = solve(
+      mode_at(case, (step - 1) as f64 * DELTA_TIME),
+      leader,
+      Integrator::VelocityVerlet,
+      0.0,
+      MASS,
+      MAX_THRUST,
+      &mut position,
+      &mut velocity,
+      &mut rotation,
+      &mut rotational_velocity,
+    );
+    impulse += thrust.length() * DELTA_TIME;
+
+    if let Some(leader) = leader.as_mut() {
+      leader.position += leader.velocity * DELTA_TIME;
+    }
+
+    let elapsed = step as f64 * DELTA_TIME;
+    let (goal_position, goal_velocity) = goal(case, position, leader, elapsed);
+    let error = position - goal_position;
+
+    // Distance past the goal along the initial approach direction
+    overshoot = overshoot.max(error.dot(&approach));
+
+    if error.length() <= case.tolerance && (velocity - goal_velocity).length() <= SETTLED_SPEED {
+      convergence_time.get_or_insert(elapsed);
+    } else {
+      convergence_time = None; // Must arrive and stay
+    }
+  }
+
+  let (goal_position, goal_velocity) = goal(case, position, leader, case.duration);
+  Report {
+    convergence_time,
+    overshoot,
+    final_error: (position - goal_position).length(),
+    final_speed: (velocity - goal_velocity).length(),
+    propellant: impulse / EXHAUST_VELOCITY,
+  }
+}
+
+const BASE: Case = Case {
+  mode: FlightMode::Hold,
+  position: DVec3 { x: 0.0, y: 0.0, z: 0.0 },
+  velocity: DVec3 { x: 0.0, y: 0.0, z: 0.0 },
+  rotation: DVec3 { x: 0.0, y: 0.0, z: 0.0 },
+  leader: None,
+  duration: 120.0,
+  tolerance: 0.5,
+  max_convergence_time: 0.0,
+  max_overshoot: 0.0,
+  max_final_error: 0.0,
+  max_final_speed: 0.0,
+  max_propellant: 0.0,
+};
+
+/// Limits for flying to a fixed point, which all navigation cases share
+const NAVIGATE: Case = Case {
+  max_overshoot: 0.25,
+  max_final_error: 0.05,
+  max_final_speed: 0.05,
+  ..BASE
+};
+
+fn assert_meets_targets(case: Case) {
+  let report = fly(&case);
+
+  let convergence_time = report.convergence_time.expect("never settled on the goal");
+  assert!(
+    convergence_time <= case.max_convergence_time,
+    "settled after {convergence_time}s, limit {}s",
+    case.max_convergence_time
+  );
+  assert!(report.overshoot <= case.max_overshoot, "overshot by {:.3}m, limit {}m", report.overshoot, case.max_overshoot);
+  assert!(
+    report.final_error <= case.max_final_error,
+    "ended {:.3}m off, limit {}m",
+    report.final_error,
+    case.max_final_error
+  );
+  assert!(
+    report.final_speed <= case.max_final_speed,
+    "ended at {:.3}m/s, limit {}m/s",
+    report.final_speed,
+    case.max_final_speed
+  );
+  assert!(
+    report.propellant <= case.max_propellant,
+    "used {:.3}kg propellant, limit {}kg",
+    report.propellant,
+    case.max_propellant
+  );
+}
+
+/// Control output for a ship at rest at the origin, facing +X
+fn control_at_rest(mode: FlightMode) -> (DVec3, DVec3) {
+  calculate_flight_control(
+    mode,
+    DVec3::zero(),
+    DVec3::zero(),
+    DVec3::zero(),
+    DVec3::zero(),
+    MASS,
+    MAX_THRUST.x,
+    MAX_THRUST.y,
+    MAX_THRUST.z,
+    None,
+    DELTA_TIME,
+  )
+}
+
+#[test]
+fn navigate_dead_ahead() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(50.0, 0.0, 0.0)),
+    max_convergence_time: 36.0,
+    max_propellant: 3.0,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn navigate_behind() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(-40.0, 0.0, 10.0)),
+    max_convergence_time: 35.0,
+    max_propellant: 2.8,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn navigate_climb() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(20.0, 15.0, -10.0)),
+    max_convergence_time: 27.0,
+    max_propellant: 2.3,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn navigate_straight_up() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(0.0, 30.0, 0.0)),
+    max_convergence_time: 29.0,
+    max_propellant: 2.4,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn navigate_while_drifting() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(30.0, 0.0, 30.0)),
+    velocity: v(0.0, 0.0, -3.0),
+    max_convergence_time: 45.0,
+    max_propellant: 4.2,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn navigate_short_hop() {
+  assert_meets_targets(Case {
+    mode: FlightMode::NavigateTo(v(3.0, 0.0, 0.0)),
+    max_convergence_time: 9.0,
+    max_propellant: 0.8,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn hold_kills_drift() {
+  assert_meets_targets(Case {
+    mode: FlightMode::Hold,
+    velocity: v(1.0, -0.5, 0.5),
+    tolerance: 15.0,
+    max_convergence_time: 32.0,
+    max_final_speed: 0.01,
+    max_propellant: 0.55,
+    ..BASE
+  });
+}
+
+#[test]
+fn track_a_fixed_point() {
+  assert_meets_targets(Case {
+    mode: FlightMode::Track(v(25.0, -5.0, 25.0)),
+    max_convergence_time: 30.0,
+    max_propellant: 2.6,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn intercept_a_crossing_target() {
+  assert_meets_targets(Case {
+    mode: FlightMode::Intercept { target_pos: v(60.0, 0.0, -20.0), target_vel: v(0.0, 0.0, 0.5) },
+    tolerance: 2.0,
+    duration: 180.0,
+    max_convergence_time: 54.0,
+    max_overshoot: 0.4,
+    max_final_error: 1.2,
+    max_propellant: 4.5,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn formation_join_from_behind() {
+  assert_meets_targets(Case {
+    mode: FlightMode::Formation { offset: v(-10.0, 0.0, 5.0) },
+    position: v(-30.0, 0.0, 0.0),
+    leader: Some(LeaderState { position: DVec3::zero(), velocity: v(1.0, 0.0, 0.0), rotation: DVec3::zero() }),
+    tolerance: 1.0,
+    max_convergence_time: 73.0,
+    max_overshoot: 10.0,
+    max_propellant: 4.6,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn formation_hold_slot() {
+  assert_meets_targets(Case {
+    mode: FlightMode::Formation { offset: v(0.0, 3.0, -6.0) },
+    position: v(0.0, 3.0, -6.0),
+    velocity: v(0.5, 0.0, 0.0),
+    leader: Some(LeaderState { position: DVec3::zero(), velocity: v(0.5, 0.0, 0.0), rotation: DVec3::zero() }),
+    max_convergence_time: 1.0,
+    max_overshoot: 0.05,
+    max_propellant: 0.05,
+    ..NAVIGATE
+  });
+}
+
+#[test]
+fn attitude_torque_is_proportional_to_the_heading_error() {
+  // 0.1 rad of yaw to go, at a gain of 25
+  let target = v(0.1_f64.cos(), 0.0, 0.1_f64.sin()) * 50.0;
+  let (_, torque) = control_at_rest(FlightMode::NavigateTo(target));
+  assert!((torque.y - 2.5).abs() < 1e-9, "yaw torque {}", torque.y);
+  assert!(torque.x.abs() < 1e-9);
+}
+
+#[test]
+fn no_main_thrust_until_facing_the_target() {
+  // cos 53° = 0.6, below the 0.7 alignment the main drive waits for
+  let (thrust, _) = control_at_rest(FlightMode::NavigateTo(v(30.0, 0.0, 40.0)));
+  assert_eq!(thrust.x, 0.0);
+
+  // cos 37° = 0.8
+  let (thrust, _) = control_at_rest(FlightMode::NavigateTo(v(40.0, 0.0, 30.0)));
+  assert!(thrust.x > 0.0);
+}
+
+#[test]
+fn manual_thrust_is_clamped_per_axis() {
+  let (thrust, _) = control_at_rest(FlightMode::Manual(v(1000.0, 1000.0, -1000.0)));
+  assert_eq!(thrust, v(400.0, 150.0, -150.0));
+
+  // Backwards is limited by the retro thrusters
+  let (thrust, _) = control_at_rest(FlightMode::Manual(v(-1000.0, 0.0, 0.0)));
+  assert_eq!(thrust, v(-250.0, 0.0, 0.0));
+}
+
+#[test]
+fn manual_thrust_applies_no_torque() {
+  let (_, torque) = control_at_rest(FlightMode::Manual(v(400.0, 100.0, 100.0)));
+  assert_eq!(torque, DVec3::zero());
+}
+
+#[test]
+fn manual_thrust_is_in_the_body_frame() {
+  // Yawed a quarter turn, forward is +Z
+  let mut position = DVec3::zero();
+  let mut velocity = DVec3::zero();
+  let mut rotation = v(0.0, std::f64::consts::FRAC_PI_2, 0.0);
+  let mut rotational_velocity = DVec3::zero();
+  for _ in 0..20 {
+    solve(
+      FlightMode::Manual(v(400.0, 0.0, 0.0)),
+      None,
+      Integrator::VelocityVerlet,
+      0.0,
+      MASS,
+      MAX_THRUST,
+      &mut position,
+      &mut velocity,
+      &mut rotation,
+      &mut rotational_velocity,
+    );
+  }
+
+  // A second at full main thrust
+  assert!((velocity.z - 0.4).abs() < 1e-9, "velocity {velocity:?}");
+  assert!(velocity.x.abs() < 1e-9 && velocity.y.abs() < 1e-9, "velocity {velocity:?}");
+}
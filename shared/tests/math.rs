@@ -0,0 +1,218 @@
+//! Tests for the f64 vector/quaternion math in `shared::math`.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use shared::math::{DQuat, DVec3, Frame};
+
+const EPSILON: f64 = 1e-9;
+
+fn v(x: f64, y: f64, z: f64) -> DVec3 {
+  DVec3::new(x, y, z)
+}
+
+fn assert_vec_eq(actual: DVec3, expected: DVec3) {
+  assert!(
+    (actual - expected).length() < EPSILON,
+    "expected {:?}, got {:?}",
+    expected,
+    actual
+  );
+}
+
+/// Quaternions q and -q are the same rotation
+fn assert_rotation_eq(actual: DQuat, expected: DQuat) {
+  assert!(
+    actual.angle_between(&expected) < 1e-6,
+    "expected {:?}, got {:?}",
+    expected,
+    actual
+  );
+}
+
+#[test]
+fn vector_operators() {
+  let a = v(1.0, 2.0, 3.0);
+  let b = v(-4.0, 0.5, 2.0);
+
+  assert_vec_eq(a + b, v(-3.0, 2.5, 5.0));
+  assert_vec_eq(a - b, v(5.0, 1.5, 1.0));
+  assert_vec_eq(-a, v(-1.0, -2.0, -3.0));
+  assert_vec_eq(a * 2.0, v(2.0, 4.0, 6.0));
+  assert_vec_eq(2.0 * a, v(2.0, 4.0, 6.0));
+  assert_vec_eq(a / 2.0, v(0.5, 1.0, 1.5));
+
+  let mut c = a;
+  c += b;
+  c -= a;
+  c *= 3.0;
+  assert_vec_eq(c, b * 3.0);
+
+  assert_eq!(a.dot(&b), -4.0 + 1.0 + 6.0);
+  assert_vec_eq(DVec3::X.cross(&DVec3::Y), DVec3::Z);
+  assert_vec_eq(a.lerp(&b, 0.5), v(-1.5, 1.25, 2.5));
+  assert!((v(3.0, 4.0, 0.0).distance(&DVec3::ZERO) - 5.0).abs() < EPSILON);
+}
+
+#[test]
+fn vector_normalisation() {
+  let n = v(3.0, 0.0, 4.0).normalize();
+  assert_vec_eq(n, v(0.6, 0.0, 0.8));
+  assert!((n.length() - 1.0).abs() < EPSILON);
+
+  // Zero stays zero rather than turning into NaN
+  assert_eq!(DVec3::ZERO.normalize(), DVec3::ZERO);
+
+  for axis in [DVec3::X, DVec3::Y, DVec3::Z, v(1.0, 1.0, 1.0).normalize()] {
+    let perp = axis.any_orthogonal();
+    assert!(perp.dot(&axis).abs() < EPSILON);
+    assert!((perp.length() - 1.0).abs() < EPSILON);
+  }
+}
+
+#[test]
+fn quaternion_rotates_vectors() {
+  let quarter_turn_about_y = DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2);
+  assert_vec_eq(quarter_turn_about_y * DVec3::X, v(0.0, 0.0, -1.0));
+  assert_vec_eq(quarter_turn_about_y * DVec3::FORWARD, v(-1.0, 0.0, 0.0));
+  assert_vec_eq(DQuat::IDENTITY * v(1.0, 2.0, 3.0), v(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn quaternion_product_composes_rotations() {
+  let a = DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2);
+  let b = DQuat::from_axis_angle(DVec3::X, FRAC_PI_2);
+  let p = v(0.3, -1.2, 2.0);
+
+  // (a * b) applies b first, then a
+  assert_vec_eq((a * b) * p, a * (b * p));
+  assert_rotation_eq(a * a.inverse(), DQuat::IDENTITY);
+  assert_rotation_eq(a.conjugate(), a.inverse());
+}
+
+#[test]
+fn quaternion_normalisation() {
+  let q = DQuat::from_xyzw(0.0, 2.0, 0.0, 2.0).normalize();
+  assert!((q.length() - 1.0).abs() < EPSILON);
+  assert_rotation_eq(q, DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2));
+  assert_eq!(DQuat::from_xyzw(0.0, 0.0, 0.0, 0.0).normalize(), DQuat::IDENTITY);
+}
+
+#[test]
+fn axis_angle_round_trip() {
+  let axis = v(1.0, -2.0, 0.5).normalize();
+  for angle in [0.1, 1.0, FRAC_PI_2, 3.0] {
+    let (out_axis, out_angle) = DQuat::from_axis_angle(axis, angle).to_axis_angle();
+    assert_vec_eq(out_axis, axis);
+    assert!((out_angle - angle).abs() < EPSILON, "{} != {}", out_angle, angle);
+  }
+
+  // More than half a turn comes back as the shorter rotation the other way
+  let (out_axis, out_angle) = DQuat::from_axis_angle(axis, 1.5 * PI).to_axis_angle();
+  assert_vec_eq(out_axis, -axis);
+  assert!((out_angle - 0.5 * PI).abs() < EPSILON);
+
+  let (_, no_angle) = DQuat::IDENTITY.to_axis_angle();
+  assert_eq!(no_angle, 0.0);
+}
+
+#[test]
+fn rotation_arc_turns_one_direction_onto_another() {
+  let cases = [
+    (DVec3::FORWARD, DVec3::X),
+    (DVec3::FORWARD, v(1.0, 1.0, -1.0).normalize()),
+    (DVec3::FORWARD, DVec3::FORWARD),
+    (DVec3::FORWARD, DVec3::Z), // Opposite
+    (DVec3::X, -DVec3::X),      // Opposite with the fallback axis
+  ];
+  for (from, to) in cases {
+    let q = DQuat::from_rotation_arc(from, to);
+    assert!((q.length() - 1.0).abs() < EPSILON);
+    assert_vec_eq(q * from, to);
+  }
+}
+
+#[test]
+fn look_rotation_points_forward_and_keeps_up() {
+  let forward = v(1.0, 0.0, 0.0);
+  let q = DQuat::look_rotation(forward, DVec3::Y);
+  assert_vec_eq(q * DVec3::FORWARD, forward);
+  assert_vec_eq(q * DVec3::Y, DVec3::Y);
+
+  let forward = v(1.0, 2.0, -3.0).normalize();
+  let q = DQuat::look_rotation(forward, DVec3::Y);
+  assert_vec_eq(q * DVec3::FORWARD, forward);
+  // Up stays in the plane of forward and world up, on the upper side
+  let up = q * DVec3::Y;
+  assert!(up.dot(&forward).abs() < EPSILON);
+  assert!(up.dot(&DVec3::Y) > 0.0);
+  assert!(forward.cross(&DVec3::Y).dot(&up).abs() < EPSILON);
+
+  // Looking straight up falls back to the shortest arc
+  let q = DQuat::look_rotation(DVec3::Y, DVec3::Y);
+  assert_vec_eq(q * DVec3::FORWARD, DVec3::Y);
+}
+
+#[test]
+fn slerp_interpolates_along_the_shorter_arc() {
+  let start = DQuat::IDENTITY;
+  let end = DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2);
+
+  assert_rotation_eq(start.slerp(&end, 0.0), start);
+  assert_rotation_eq(start.slerp(&end, 1.0), end);
+  assert_rotation_eq(start.slerp(&end, 0.5), DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2 / 2.0));
+
+  // -end is the same rotation; slerp must not take the long way round
+  assert_rotation_eq(start.slerp(&-end, 0.5), DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2 / 2.0));
+
+  // Nearly identical rotations use the normalised lerp path
+  let nearly = DQuat::from_axis_angle(DVec3::Y, 1e-4);
+  assert!((start.slerp(&nearly, 0.5).length() - 1.0).abs() < EPSILON);
+
+  assert!((start.angle_between(&end) - FRAC_PI_2).abs() < EPSILON);
+  assert_vec_eq(start.axis_to(&end), DVec3::Y);
+}
+
+#[test]
+fn frame_transforms_round_trip() {
+  let frame = Frame::new(v(10.0, 0.0, -5.0), DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2));
+  let local = v(1.0, 2.0, 3.0);
+
+  let parent = frame.point_to_parent(local);
+  assert_vec_eq(parent, v(10.0 + 3.0, 2.0, -5.0 - 1.0));
+  assert_vec_eq(frame.point_from_parent(parent), local);
+
+  // Directions ignore the frame's position
+  assert_vec_eq(frame.vector_to_parent(DVec3::X), v(0.0, 0.0, -1.0));
+  assert_vec_eq(frame.vector_from_parent(v(0.0, 0.0, -1.0)), DVec3::X);
+
+  assert_vec_eq(frame.inverse().point_to_parent(parent), local);
+}
+
+#[test]
+fn frames_compose_through_a_hierarchy() {
+  let planet = Frame::new(v(100.0, 0.0, 0.0), DQuat::from_axis_angle(DVec3::Y, FRAC_PI_2));
+  let moon = Frame::new(v(0.0, 0.0, -10.0), DQuat::from_axis_angle(DVec3::X, 0.3));
+  let ship = v(1.0, 1.0, 1.0);
+
+  let via_each = planet.point_to_parent(moon.point_to_parent(ship));
+  let composed = planet.compose(&moon);
+  assert_vec_eq(composed.point_to_parent(ship), via_each);
+  assert_vec_eq(composed.point_from_parent(via_each), ship);
+  assert_eq!(Frame::IDENTITY.compose(&moon), moon);
+}
+
+#[cfg(feature = "bevy")]
+#[test]
+fn bevy_conversions_are_lossless() {
+  let original = v(1.0e12 + 0.125, -3.5e-9, 7.0);
+  let bevy: bevy_math::DVec3 = original.into();
+  assert_eq!(DVec3::from(bevy), original);
+
+  let rotation = DQuat::from_axis_angle(v(0.2, 1.0, -0.4), 1.234);
+  let bevy: bevy_math::DQuat = rotation.into();
+  assert_eq!(DQuat::from(bevy), rotation);
+
+  // Both sides agree on what the rotation does
+  let rotated: DVec3 = (bevy * bevy_math::DVec3::from(original)).into();
+  assert!((rotated - rotation * original).length() < 1e-3);
+}
@@ -0,0 +1,104 @@
+//! Client-side prediction: replaying unacknowledged inputs must land exactly
+//! where the server would.
+
+use shared::math::{DQuat, DVec3};
+use shared::piloting::{self, InputBuffer, ShipParameters};
+use shared::trajectory::{FlightState, Integrator};
+
+const PARAMETERS: ShipParameters = ShipParameters {
+  integrator: Integrator::VelocityVerlet,
+  gravitational_parameter: 0.0,
+  mass: 1000.0,
+  max_thrust: DVec3 { x: 400.0, y: 250.0, z: 150.0 },
+};
+
+fn start() -> FlightState {
+  FlightState::from_pose(DVec3::new(0.0, 5.0, -5.0), DVec3::ZERO, DQuat::IDENTITY, DVec3::ZERO)
+}
+
+fn thrust_at(step: usize) -> DVec3 {
+  match step % 3 {
+    0 => DVec3::new(400.0, 0.0, 0.0),
+    1 => DVec3::new(0.0, 100.0, -50.0),
+    _ => DVec3::new(-250.0, 0.0, 0.0),
+  }
+}
+
+#[test]
+fn sequences_start_at_one_and_acknowledge_drops_older_inputs() {
+  let mut buffer = InputBuffer::default();
+  for step in 0..5 {
+    let input = buffer.record(thrust_at(step));
+    assert_eq!(input.sequence, step as u32 + 1);
+  }
+
+  buffer.acknowledge(0);
+  assert_eq!(buffer.len(), 5);
+  buffer.acknowledge(3);
+  assert_eq!(buffer.pending().map(|input| input.sequence).collect::<Vec<_>>(), vec![4, 5]);
+  buffer.acknowledge(10);
+  assert!(buffer.is_empty());
+}
+
+#[test]
+fn resumed_buffer_numbers_after_the_acknowledged_input() {
+  let mut buffer = InputBuffer::resume(41);
+  assert_eq!(buffer.record(DVec3::ZERO).sequence, 42);
+}
+
+#[test]
+fn replay_matches_the_authoritative_simulation() {
+  let mut buffer = InputBuffer::default();
+  let mut server = start();
+  let mut server_after_ack = start();
+
+  // The server has applied the first 6 of 10 inputs when its state arrives
+  for step in 0..10 {
+    let input = buffer.record(thrust_at(step));
+    piloting::step(&mut server, input.thrust, &PARAMETERS);
+    if input.sequence == 6 {
+      server_after_ack = server;
+    }
+  }
+
+  let predicted = buffer.reconcile(6, server_after_ack, &PARAMETERS);
+  assert_eq!(buffer.len(), 4);
+  assert!(predicted.position.distance(&server.position) < 1e-12);
+  assert!(predicted.velocity.distance(&server.velocity) < 1e-12);
+}
+
+#[test]
+fn pose_round_trips_through_solver_angles() {
+  let orientation = DQuat::from_axis_angle(DVec3::Y, 0.7)
+    * DQuat::from_axis_angle(DVec3::X, 0.3)
+    * DQuat::from_axis_angle(DVec3::FORWARD, 0.4);
+  let state = FlightState::from_pose(DVec3::ZERO, DVec3::ZERO, orientation, DVec3::ZERO);
+  // Roll included, not just the heading
+  for axis in [DVec3::FORWARD, DVec3::Y] {
+    assert!((state.orientation() * axis).distance(&(orientation * axis)) < 1e-9);
+  }
+  assert!((state.rotation.z - 0.4).abs() < 1e-9);
+}
+
+#[test]
+fn angular_velocity_round_trips_through_solver_rates() {
+  let orientation = DQuat::from_axis_angle(DVec3::Y, -1.2) * DQuat::from_axis_angle(DVec3::X, 0.5);
+  let spin = DVec3::new(0.2, -0.1, 0.3);
+  let state = FlightState::from_pose(DVec3::ZERO, DVec3::ZERO, orientation, spin);
+  assert!(state.angular_velocity().distance(&spin) < 1e-9);
+}
+
+#[test]
+fn solver_rates_turn_the_ship_like_the_angular_velocity() {
+  let orientation = DQuat::from_axis_angle(DVec3::Y, 0.9) * DQuat::from_axis_angle(DVec3::X, -0.4);
+  let spin = DVec3::new(0.3, 0.2, -0.1);
+  let mut state = FlightState::from_pose(DVec3::ZERO, DVec3::ZERO, orientation, spin);
+
+  let time = 1e-6;
+  state.rotation += state.rotational_velocity * time;
+  // Body-frame spin turns the ship about its own axes
+  let expected = orientation * DQuat::from_axis_angle(spin, spin.length() * time);
+  for axis in [DVec3::FORWARD, DVec3::Y] {
+    assert!((state.orientation() * axis).distance(&(expected * axis)) < 1e-11);
+  }
+}